@@ -5,6 +5,7 @@ use hexgrid::{
     edge::{bounds::EdgeBounds, position::EdgePosition, table::EdgeTable},
     hex::{bounds::HexBounds, position::HexPosition, table::HexTable},
 };
+use serde::{Deserialize, Serialize};
 
 use crate::{
     game::{GameRng, edition::GameEdition},
@@ -19,7 +20,7 @@ use crate::{
     },
 };
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Board {
     tiles: HexTable<TileData>,
     corners: CornerTable<CornerInfo>,
@@ -29,10 +30,10 @@ pub struct Board {
 }
 
 impl Board {
-    pub fn new(edition: impl GameEdition, rng: &mut GameRng) -> Self {
-        let tiles = Self::create_tiles(&edition, rng);
+    pub fn new(edition: &impl GameEdition, rng: &mut GameRng) -> Self {
+        let tiles = Self::create_tiles(edition, rng);
         let bounds = tiles.get_bounds();
-        let corners = Self::create_trades(bounds, &edition, rng);
+        let corners = Self::create_trades(bounds, edition, rng);
         let edges = EdgeTable::new(EdgeBounds::new(bounds));
         let robber = Robber::place(&tiles);
         let trade_ports = edition.get_trades(rng).collect();
@@ -65,6 +66,26 @@ impl Board {
         }
     }
 
+    pub fn contains_hex(&self, position: HexPosition) -> bool {
+        self.tiles.contains(position)
+    }
+
+    pub fn contains_corner(&self, position: CornerPosition) -> bool {
+        self.corners.contains(position)
+    }
+
+    pub fn contains_edge(&self, position: EdgePosition) -> bool {
+        self.edges.contains(position)
+    }
+
+    pub fn hex_positions(&self) -> impl Iterator<Item = HexPosition> {
+        self.tiles.positions()
+    }
+
+    pub fn corner_positions(&self) -> impl Iterator<Item = CornerPosition> {
+        self.corners.get_bounds().area()
+    }
+
     pub fn get_tile_data(&self) -> impl Iterator<Item = TileData> {
         self.tiles.positions().map(|p| self.get_tile(p))
     }
@@ -121,6 +142,10 @@ impl Board {
         self.robber.r#move(position);
     }
 
+    pub fn get_robber(&self) -> HexPosition {
+        self.robber.position()
+    }
+
     pub fn has_robber(&self, position: HexPosition) -> bool {
         self.robber.position() == position
     }
@@ -0,0 +1,242 @@
+use hexgrid::{
+    corner::position::CornerPosition, edge::position::EdgePosition, hex::position::HexPosition,
+};
+
+use crate::{
+    bot::Bot,
+    game::{
+        action::{Action, DevelopmentCardPlay},
+        player::OwnershipToken,
+        view::PlayerView,
+    },
+    object::{
+        card::ResourceMap,
+        resource::{ResourceType, Resources},
+        structure::{StructureType, building::BuildingType},
+        trade::TradeType,
+    },
+};
+
+/// Extra value of a settlement spot for every resource the player does not produce yet.
+const DIVERSITY_WEIGHT: f64 = 2.;
+/// Value of a port which accepts any resource.
+const ANY_PORT_WEIGHT: f64 = 2.;
+/// Value of a resource port, before adding what the player produces of that resource.
+const RESOURCE_PORT_WEIGHT: f64 = 1.;
+
+/// Greedily picks the action with the highest score. Settlement spots are valued by the pips of their neighboring
+/// hexes, the resources they add to the player's production and their port access. Other actions follow a fixed
+/// priority: cities, settlements, development cards, then roads towards the best free spot.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HeuristicBot;
+
+impl HeuristicBot {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn score(&self, view: &PlayerView, action: Action) -> f64 {
+        let player = view.viewer();
+        let held = view.player().get_resources();
+
+        match action {
+            Action::RollDice => 1000.,
+            Action::BuildCity(corner) => 200. + corner_pips(view, corner),
+            Action::BuildSettlement(corner) => 100. + corner_value(view, player, corner),
+            Action::BuildRoad(edge) | Action::BuildBoat(edge) => {
+                // Roads only pay off once there is a spot to settle, so keep the last settlement's cost in hand.
+                if view.player().count_structure(StructureType::Settlement) == 0 {
+                    return -1.;
+                }
+                10. + best_spot_from(view, player, edge)
+            }
+            Action::BuyDevelopmentCard => 30.,
+            Action::PlayDevelopmentCard(play) => match play {
+                DevelopmentCardPlay::MoveRobber if is_blocked(view, player) => 60.,
+                DevelopmentCardPlay::MoveRobber => 5.,
+                DevelopmentCardPlay::TakeTwoResources(first, second) => {
+                    let need = goal(view, player) - held;
+                    40. + (need.get(first).get_count() + need.get(second).get_count()) as f64
+                }
+                DevelopmentCardPlay::Monopoly(resource) => {
+                    let taken = view.unseen_resources().get(resource).get_count();
+                    20. + 5. * taken as f64
+                }
+                // Hidden victory points already count towards winning, so there is no reason to reveal them.
+                DevelopmentCardPlay::VictoryPoint => -1.,
+                DevelopmentCardPlay::BuildRoads => 35.,
+            },
+            Action::Discard(discard) => {
+                let need = goal(view, player) - held;
+                -Resources::new()
+                    .into_iter()
+                    .map(|r| {
+                        let weight = 1. + need.get(r).get_count() as f64
+                            - held.get(r).get_count() as f64 / 10.;
+                        discard.get(r).get_count() as f64 * weight
+                    })
+                    .sum::<f64>()
+            }
+            Action::MoveRobber(hex) => robber_value(view, player, hex),
+            Action::Steal(victim) => {
+                view.find_opponent(victim).map_or(0, |o| o.resource_count()) as f64
+            }
+            Action::TradeWithBank { give, receive } => {
+                let goal = goal(view, player);
+                let need = goal - held;
+                let is_needed = Resources::new()
+                    .into_iter()
+                    .any(|r| receive.get(r).get_count() > 0 && need.get(r).get_count() > 0);
+                let is_surplus = Resources::new().into_iter().all(|r| {
+                    held.get(r).get_count() >= give.get(r).get_count() + goal.get(r).get_count()
+                });
+                if is_needed && is_surplus { 20. } else { -1. }
+            }
+            Action::AcceptTrade(offer) => {
+                let Some(offer) = view.find_trade_offer(offer) else {
+                    return -1.;
+                };
+                // The proposer's side of the offer is what we receive, and the other way around.
+                let goal = goal(view, player);
+                let after = held - offer.receive() + offer.give();
+                if (goal - after).total() < (goal - held).total() {
+                    15.
+                } else {
+                    -1.
+                }
+            }
+            Action::RejectTrade(_) => 0.,
+            Action::EndTurn => 0.,
+            Action::ProposeTrade { .. }
+            | Action::CounterTrade { .. }
+            | Action::WithdrawTrade(_) => -1.,
+        }
+    }
+}
+
+impl Bot for HeuristicBot {
+    fn choose_action(&mut self, view: &PlayerView) -> Action {
+        view.legal_actions()
+            .iter()
+            .map(|a| (*a, self.score(view, *a)))
+            .reduce(|best, next| if next.1 > best.1 { next } else { best })
+            .expect("Bots are only asked to act when they have a legal action!")
+            .0
+    }
+}
+
+/// The number of ways two dice can roll the number, out of 36.
+fn pips(roll_number: u32) -> f64 {
+    6. - (7. - roll_number as f64).abs()
+}
+
+fn hex_pips(view: &PlayerView, hex: HexPosition) -> f64 {
+    view.get_tile_type(hex).get_roll_number().map_or(0., pips)
+}
+
+fn corner_pips(view: &PlayerView, corner: CornerPosition) -> f64 {
+    corner
+        .neighboring_hex()
+        .into_iter()
+        .map(|h| hex_pips(view, h))
+        .sum()
+}
+
+/// The resources the player already produces, with their total pips.
+fn production(view: &PlayerView, player: OwnershipToken) -> Vec<(ResourceType, f64)> {
+    let mut production: Vec<(ResourceType, f64)> = Vec::new();
+
+    for (_, corner) in view.buildings().filter(|(b, _)| b.owner() == player) {
+        for hex in corner.neighboring_hex() {
+            if let Some(resource) = view.get_tile_type(hex).get_resource_type() {
+                match production.iter_mut().find(|(r, _)| *r == resource) {
+                    Some((_, total)) => *total += hex_pips(view, hex),
+                    None => production.push((resource, hex_pips(view, hex))),
+                }
+            }
+        }
+    }
+
+    production
+}
+
+fn corner_value(view: &PlayerView, player: OwnershipToken, corner: CornerPosition) -> f64 {
+    let production = production(view, player);
+    let produced = |resource: ResourceType| {
+        production
+            .iter()
+            .find(|(r, _)| *r == resource)
+            .map_or(0., |(_, pips)| *pips)
+    };
+
+    let mut new_resources: Vec<ResourceType> = Vec::new();
+    for hex in corner.neighboring_hex() {
+        if let Some(resource) = view.get_tile_type(hex).get_resource_type()
+            && produced(resource) == 0.
+            && !new_resources.contains(&resource)
+        {
+            new_resources.push(resource);
+        }
+    }
+
+    let port = match view.get_trade(corner) {
+        Some(TradeType::Any) => ANY_PORT_WEIGHT,
+        Some(TradeType::Resource(resource)) => RESOURCE_PORT_WEIGHT + produced(resource) / 3.,
+        None => 0.,
+    };
+
+    corner_pips(view, corner) + DIVERSITY_WEIGHT * new_resources.len() as f64 + port
+}
+
+/// The value of the best spot a road on this edge leads to, if any can still be settled.
+fn best_spot_from(view: &PlayerView, player: OwnershipToken, edge: EdgePosition) -> f64 {
+    edge.neighboring_corners()
+        .into_iter()
+        .filter(|c| {
+            view.find_building(*c).is_none()
+                && c.neighboring_corners()
+                    .into_iter()
+                    .all(|n| view.find_building(n).is_none())
+        })
+        .map(|c| corner_value(view, player, c))
+        .fold(0., f64::max)
+}
+
+/// Returns true if the robber is on a hex next to one of the player's buildings.
+fn is_blocked(view: &PlayerView, player: OwnershipToken) -> bool {
+    let robber = view.get_robber();
+    view.buildings()
+        .any(|(b, c)| b.owner() == player && c.neighboring_hex().contains(&robber))
+}
+
+/// Blocking a hex is worth its pips for every opponent building next to it, and costs as much for our own.
+fn robber_value(view: &PlayerView, player: OwnershipToken, hex: HexPosition) -> f64 {
+    view.buildings()
+        .filter(|(_, c)| c.neighboring_hex().contains(&hex))
+        .map(|(b, _)| {
+            let weight = match b.r#type() {
+                BuildingType::Settlement => 1.,
+                BuildingType::City => 2.,
+            };
+            if b.owner() == player {
+                -2. * weight
+            } else {
+                weight
+            }
+        })
+        .sum::<f64>()
+        * hex_pips(view, hex)
+}
+
+/// The cost of the next thing worth building: a city if there is a settlement to upgrade, otherwise a settlement.
+fn goal(view: &PlayerView, player: OwnershipToken) -> ResourceMap {
+    let has_settlement = view
+        .buildings()
+        .any(|(b, _)| b.owner() == player && b.r#type() == BuildingType::Settlement);
+
+    if has_settlement {
+        StructureType::City.cost()
+    } else {
+        StructureType::Settlement.cost()
+    }
+}
@@ -0,0 +1,241 @@
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::{Duration, Instant};
+
+use rand::prelude::*;
+
+use crate::{
+    bot::{Bot, acting_player, heuristic::HeuristicBot},
+    game::{Game, GameRng, action::Action, player::OwnershipToken, view::PlayerView},
+};
+
+/// Balances trying the moves which did well so far against moves which have barely been tried, for rewards between
+/// 0 and 1.
+const DEFAULT_EXPLORATION: f64 = 0.7;
+const DEFAULT_ROLLOUT_LIMIT: usize = 100;
+
+/// How much searching the bot does before each action.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchBudget {
+    Iterations(u32),
+    /// Searches for as many iterations as fit into the duration. The bot is no longer reproducible with this budget,
+    /// since the number of iterations depends on the machine. Not available on wasm32, where there is no clock to
+    /// read.
+    #[cfg(not(target_arch = "wasm32"))]
+    Time(Duration),
+}
+
+/// A node of the search tree, reached by one player applying one action.
+struct Node {
+    /// `None` for the root.
+    edge: Option<(OwnershipToken, Action)>,
+    children: Vec<usize>,
+    visits: u32,
+    /// How often the action was legal when the parent was visited, as it depends on the determinization.
+    available: u32,
+    /// The sum of the rewards for the player who applied the action.
+    reward: f64,
+}
+
+impl Node {
+    fn new(edge: Option<(OwnershipToken, Action)>) -> Self {
+        Self {
+            edge,
+            children: Vec::new(),
+            visits: 0,
+            available: 1,
+            reward: 0.,
+        }
+    }
+
+    fn upper_confidence_bound(&self, exploration: f64) -> f64 {
+        let visits = self.visits as f64;
+        self.reward / visits + exploration * ((self.available as f64).ln() / visits).sqrt()
+    }
+}
+
+/// Searches ahead with Monte Carlo tree search. The cards the player cannot see and the dice are guessed anew for
+/// every iteration (see `PlayerView::determinize`), while the statistics of all guesses are gathered in a single tree.
+/// Playouts below the tree follow the `HeuristicBot` and are scored by each player's share of the victory points once
+/// the game ends or the rollout limit is reached.
+pub struct MctsBot {
+    rng: GameRng,
+    budget: SearchBudget,
+    exploration: f64,
+    rollout_limit: usize,
+    rollout_policy: HeuristicBot,
+}
+
+impl MctsBot {
+    pub fn new(rng: GameRng, budget: SearchBudget) -> Self {
+        Self {
+            rng,
+            budget,
+            exploration: DEFAULT_EXPLORATION,
+            rollout_limit: DEFAULT_ROLLOUT_LIMIT,
+            rollout_policy: HeuristicBot::new(),
+        }
+    }
+
+    pub fn with_exploration(self, exploration: f64) -> Self {
+        Self {
+            exploration,
+            ..self
+        }
+    }
+
+    /// The number of actions played out below the tree before the game is scored.
+    pub fn with_rollout_limit(self, rollout_limit: usize) -> Self {
+        Self {
+            rollout_limit,
+            ..self
+        }
+    }
+
+    fn search(&mut self, view: &PlayerView) -> Vec<Node> {
+        let mut tree = vec![Node::new(None)];
+
+        match self.budget {
+            SearchBudget::Iterations(iterations) => {
+                for _ in 0..iterations.max(1) {
+                    self.iterate(view, &mut tree);
+                }
+            }
+            #[cfg(not(target_arch = "wasm32"))]
+            SearchBudget::Time(duration) => {
+                let start = Instant::now();
+                loop {
+                    self.iterate(view, &mut tree);
+                    if start.elapsed() >= duration {
+                        break;
+                    }
+                }
+            }
+        }
+
+        tree
+    }
+
+    /// Selects a path down the tree, adds one node to it, plays the game out from there and updates the path with the
+    /// outcome.
+    fn iterate(&mut self, view: &PlayerView, tree: &mut Vec<Node>) {
+        let mut state = view.determinize(&mut self.rng);
+
+        let mut path = vec![0];
+        let mut node = 0;
+
+        while let Some(actor) = acting_player(&state) {
+            let mut untried = Vec::new();
+            let mut best: Option<(usize, f64)> = None;
+
+            for action in state.legal_actions(actor) {
+                let child = tree[node]
+                    .children
+                    .iter()
+                    .copied()
+                    .find(|c| tree[*c].edge == Some((actor, action)));
+
+                match child {
+                    Some(child) => {
+                        tree[child].available += 1;
+                        let bound = tree[child].upper_confidence_bound(self.exploration);
+                        if best.is_none_or(|(_, b)| bound > b) {
+                            best = Some((child, bound));
+                        }
+                    }
+                    None => untried.push(action),
+                }
+            }
+
+            if let Some(action) = untried.choose(&mut self.rng).copied() {
+                state
+                    .apply(actor, action)
+                    .expect("Legal actions can always be applied!");
+
+                tree.push(Node::new(Some((actor, action))));
+                let child = tree.len() - 1;
+                tree[node].children.push(child);
+                path.push(child);
+                break;
+            }
+
+            let Some((child, _)) = best else {
+                break;
+            };
+            let (_, action) = tree[child].edge.expect("Only the root has no edge!");
+            state
+                .apply(actor, action)
+                .expect("Legal actions can always be applied!");
+
+            path.push(child);
+            node = child;
+        }
+
+        self.rollout(&mut state);
+
+        for node in path {
+            let node = &mut tree[node];
+            node.visits += 1;
+            if let Some((mover, _)) = node.edge {
+                node.reward += reward(&state, mover);
+            }
+        }
+    }
+
+    fn rollout(&mut self, state: &mut Game) {
+        for _ in 0..self.rollout_limit {
+            let Some(actor) = acting_player(state) else {
+                break;
+            };
+
+            let action = self.rollout_policy.choose_action(&state.view_for(actor));
+            state
+                .apply(actor, action)
+                .expect("Legal actions can always be applied!");
+        }
+    }
+}
+
+impl Bot for MctsBot {
+    fn choose_action(&mut self, view: &PlayerView) -> Action {
+        let player = view.viewer();
+        let legal = view.legal_actions();
+        if let [action] = legal {
+            return *action;
+        }
+
+        let tree = self.search(view);
+
+        tree[0]
+            .children
+            .iter()
+            .map(|c| &tree[*c])
+            .filter_map(|c| match c.edge {
+                Some((mover, action)) if mover == player && legal.contains(&action) => {
+                    Some((action, c.visits))
+                }
+                _ => None,
+            })
+            .reduce(|best, next| if next.1 > best.1 { next } else { best })
+            .map(|(action, _)| action)
+            .or(legal.first().copied())
+            .expect("Bots are only asked to act when they have a legal action!")
+    }
+}
+
+/// 1 for the winner, otherwise the player's share of all victory points if the game is still going.
+fn reward(state: &Game, player: OwnershipToken) -> f64 {
+    if let Some(winner) = state.winner() {
+        return if winner == player { 1. } else { 0. };
+    }
+
+    let total: u32 = (0..)
+        .map_while(|i| state.get_player(i))
+        .map(|p| state.victory_points(p.token()).total())
+        .sum();
+
+    if total == 0 {
+        return 0.;
+    }
+
+    state.victory_points(player).total() as f64 / total as f64
+}
@@ -0,0 +1,85 @@
+pub mod heuristic;
+pub mod mcts;
+pub mod random;
+
+#[cfg(test)]
+pub mod test;
+
+use thiserror::Error;
+
+use crate::game::{
+    Game,
+    action::Action,
+    error::ActionError,
+    phase::{RobberStep, TurnPhase},
+    player::OwnershipToken,
+    view::PlayerView,
+};
+
+/// A computer player. Bots only see the game through the view of the player they control, and are only ever asked to
+/// act when that player has at least one legal action.
+pub trait Bot {
+    fn choose_action(&mut self, view: &PlayerView) -> Action;
+}
+
+/// Returns the player the game is waiting on: someone who still has to discard, someone who has been offered a trade,
+/// or otherwise the current player.
+pub fn acting_player(game: &Game) -> Option<OwnershipToken> {
+    if let TurnPhase::GameOver { .. } = game.phase() {
+        return None;
+    }
+
+    let mut players = (0..).map_while(|i| game.get_player(i)).map(|p| p.token());
+
+    if game.phase() == TurnPhase::Robber(RobberStep::Discard) {
+        return players.find(|p| game.pending_discard(*p).is_some());
+    }
+
+    players
+        .find(|p| game.trade_offers().iter().any(|o| o.is_addressed_to(*p)))
+        .or(Some(game.current_turn()))
+}
+
+#[derive(Error, Debug, Clone)]
+pub enum PlayError {
+    #[error("{player:?} has no legal action during {phase:?}!")]
+    NoLegalAction {
+        player: OwnershipToken,
+        phase: TurnPhase,
+    },
+    #[error(transparent)]
+    Action(#[from] ActionError),
+}
+
+/// Lets the bots play against each other until someone wins or `max_actions` actions have been applied. Bots are
+/// matched to players in turn order. Returns the winner, if there is one, or an error if the game gets stuck on a
+/// player without any legal action.
+pub fn play_game(
+    game: &mut Game,
+    bots: &mut [Box<dyn Bot>],
+    max_actions: usize,
+) -> Result<Option<OwnershipToken>, PlayError> {
+    for _ in 0..max_actions {
+        let Some(player) = acting_player(game) else {
+            break;
+        };
+
+        let index = (0..)
+            .map_while(|i| game.get_player(i))
+            .position(|p| p.token() == player)
+            .expect("The acting player is part of the game!");
+
+        let view = game.view_for(player);
+        if view.legal_actions().is_empty() {
+            return Err(PlayError::NoLegalAction {
+                player,
+                phase: game.phase(),
+            });
+        }
+
+        let action = bots[index].choose_action(&view);
+        game.apply(player, action)?;
+    }
+
+    Ok(game.winner())
+}
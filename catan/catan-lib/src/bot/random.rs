@@ -0,0 +1,26 @@
+use rand::prelude::*;
+
+use crate::{
+    bot::Bot,
+    game::{GameRng, action::Action, view::PlayerView},
+};
+
+/// Picks uniformly among the legal actions.
+pub struct RandomBot {
+    rng: GameRng,
+}
+
+impl RandomBot {
+    pub fn new(rng: GameRng) -> Self {
+        Self { rng }
+    }
+}
+
+impl Bot for RandomBot {
+    fn choose_action(&mut self, view: &PlayerView) -> Action {
+        *view
+            .legal_actions()
+            .choose(&mut self.rng)
+            .expect("Bots are only asked to act when they have a legal action!")
+    }
+}
@@ -0,0 +1,90 @@
+use std::num::NonZeroUsize;
+
+use rand::SeedableRng;
+
+use crate::{
+    bot::{
+        Bot,
+        heuristic::HeuristicBot,
+        mcts::{MctsBot, SearchBudget},
+        play_game,
+        random::RandomBot,
+    },
+    game::{Game, GameRng, edition::BaseEdition},
+};
+
+#[test]
+fn random_bot_test() {
+    for seed in 0..10 {
+        let mut rng = GameRng::seed_from_u64(seed);
+        let mut game = Game::new(BaseEdition, NonZeroUsize::new(3).unwrap(), &mut rng);
+        let mut bots: Vec<Box<dyn Bot>> = (0..3)
+            .map(|i| Box::new(RandomBot::new(GameRng::seed_from_u64(seed * 3 + i))) as Box<dyn Bot>)
+            .collect();
+
+        play_game(&mut game, &mut bots, 2000).unwrap();
+        assert!(!game.phase().is_setup());
+    }
+}
+
+#[test]
+fn heuristic_bot_test() {
+    let mut heuristic_wins = 0;
+    let mut random_wins = 0;
+
+    for seed in 0..10 {
+        let mut rng = GameRng::seed_from_u64(seed);
+        let mut game = Game::new(BaseEdition, NonZeroUsize::new(2).unwrap(), &mut rng);
+        let heuristic = game.get_player(0).unwrap().token();
+        let mut bots: Vec<Box<dyn Bot>> = vec![
+            Box::new(HeuristicBot::new()),
+            Box::new(RandomBot::new(GameRng::seed_from_u64(seed))),
+        ];
+
+        match play_game(&mut game, &mut bots, 5000).unwrap() {
+            Some(winner) if winner == heuristic => heuristic_wins += 1,
+            Some(_) => random_wins += 1,
+            None => {}
+        }
+    }
+
+    assert!(
+        heuristic_wins >= 8,
+        "heuristic bot won {heuristic_wins} games, random bot {random_wins}"
+    );
+}
+
+#[test]
+fn mcts_bot_test() {
+    let play = || {
+        let mut rng = GameRng::seed_from_u64(4);
+        let mut game = Game::new(BaseEdition, NonZeroUsize::new(2).unwrap(), &mut rng);
+        let mut bots: Vec<Box<dyn Bot>> = (0..2)
+            .map(|i| {
+                Box::new(
+                    MctsBot::new(GameRng::seed_from_u64(i), SearchBudget::Iterations(20))
+                        .with_rollout_limit(20),
+                ) as Box<dyn Bot>
+            })
+            .collect();
+
+        play_game(&mut game, &mut bots, 60).unwrap();
+        game
+    };
+
+    // The search only draws from the bot's own RNG, so the same seeds play out the same game. Tokens differ between
+    // games, so players are compared by seat.
+    let summary = |game: &Game| {
+        let seats: Vec<_> = (0..2)
+            .map(|i| {
+                let player = game.get_player(i).unwrap();
+                (player.get_resources(), game.victory_points(player.token()))
+            })
+            .collect();
+        (game.turn_number(), game.get_robber(), seats)
+    };
+
+    let game = play();
+    assert!(!game.phase().is_setup());
+    assert_eq!(summary(&game), summary(&play()));
+}
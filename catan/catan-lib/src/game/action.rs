@@ -0,0 +1,71 @@
+use hexgrid::{
+    corner::position::CornerPosition, edge::position::EdgePosition, hex::position::HexPosition,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    game::{player::OwnershipToken, trade::TradeOfferId},
+    object::{
+        card::{DevelopmentCardType, ResourceMap},
+        resource::ResourceType,
+    },
+};
+
+/// Everything a player can do to change the state of a game, applied through `Game::apply`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Action {
+    RollDice,
+    BuildSettlement(CornerPosition),
+    BuildCity(CornerPosition),
+    BuildRoad(EdgePosition),
+    BuildBoat(EdgePosition),
+    /// Can be taken by any player who holds too many cards after a 7 is rolled.
+    Discard(ResourceMap),
+    MoveRobber(HexPosition),
+    Steal(OwnershipToken),
+    BuyDevelopmentCard,
+    PlayDevelopmentCard(DevelopmentCardPlay),
+    /// Offers resources to another player, or to everyone if `to` is `None`.
+    ProposeTrade {
+        to: Option<OwnershipToken>,
+        give: ResourceMap,
+        receive: ResourceMap,
+    },
+    AcceptTrade(TradeOfferId),
+    RejectTrade(TradeOfferId),
+    /// Rejects an offer and proposes different terms back to the player who made it.
+    CounterTrade {
+        offer: TradeOfferId,
+        give: ResourceMap,
+        receive: ResourceMap,
+    },
+    WithdrawTrade(TradeOfferId),
+    /// Exchanges resources with the bank, at the player's best rate for each resource given.
+    TradeWithBank {
+        give: ResourceMap,
+        receive: ResourceMap,
+    },
+    EndTurn,
+}
+
+/// A development card to play, along with the choices its effect needs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DevelopmentCardPlay {
+    MoveRobber,
+    TakeTwoResources(ResourceType, ResourceType),
+    Monopoly(ResourceType),
+    VictoryPoint,
+    BuildRoads,
+}
+
+impl DevelopmentCardPlay {
+    pub fn r#type(&self) -> DevelopmentCardType {
+        match self {
+            DevelopmentCardPlay::MoveRobber => DevelopmentCardType::MoveRobber,
+            DevelopmentCardPlay::TakeTwoResources(..) => DevelopmentCardType::TakeTwoResources,
+            DevelopmentCardPlay::Monopoly(_) => DevelopmentCardType::Monopoly,
+            DevelopmentCardPlay::VictoryPoint => DevelopmentCardType::VictoryPoint,
+            DevelopmentCardPlay::BuildRoads => DevelopmentCardType::BuildRoads,
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    game::{Game, event::GameEvent, player::OwnershipToken},
+    object::{card::ResourceMap, resource::Resources},
+};
+
+/// The supply of resource cards which are not in any player's hand. Every card a player gains or loses, other than
+/// through trading or stealing from another player, comes from or goes back to the bank.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Bank {
+    resources: ResourceMap,
+}
+
+impl Bank {
+    pub fn new(resources: ResourceMap) -> Self {
+        Self { resources }
+    }
+
+    pub fn resources(&self) -> ResourceMap {
+        self.resources
+    }
+
+    pub fn can_supply(&self, resources: &ResourceMap) -> bool {
+        self.resources.contains(resources)
+    }
+
+    fn withdraw(&mut self, resources: ResourceMap) {
+        debug_assert!(
+            self.can_supply(&resources),
+            "The bank cannot supply {resources:?}!"
+        );
+        self.resources = self.resources - resources;
+    }
+
+    fn deposit(&mut self, resources: ResourceMap) {
+        self.resources = self.resources + resources;
+    }
+}
+
+impl Game {
+    pub fn bank(&self) -> &Bank {
+        &self.bank
+    }
+
+    /// Moves resources from the bank into a player's hand. The caller must check that the bank can supply them.
+    pub(super) fn take_from_bank(&mut self, token: OwnershipToken, resources: ResourceMap) {
+        self.bank.withdraw(resources);
+        self.find_player_mut(token).add_resources(resources);
+    }
+
+    /// Moves resources from a player's hand back into the bank. The caller must check that the player holds them.
+    pub(super) fn return_to_bank(&mut self, token: OwnershipToken, resources: ResourceMap) {
+        self.find_player_mut(token).sub_resources(resources);
+        self.bank.deposit(resources);
+    }
+
+    /// Hands out the resources produced by a roll. If the bank cannot give every player all of a resource they
+    /// produced, nobody receives that resource, unless only a single player produced it, in which case they receive
+    /// whatever is left.
+    pub(super) fn produce_resources(&mut self, mut production: Vec<(OwnershipToken, ResourceMap)>) {
+        for resource in Resources::new() {
+            let available = self.bank.resources().get(resource).get_count();
+            let demand: u32 = production
+                .iter()
+                .map(|(_, r)| r.get(resource).get_count())
+                .sum();
+
+            if demand <= available {
+                continue;
+            }
+
+            let mut producers = production
+                .iter_mut()
+                .filter(|(_, r)| r.get(resource).get_count() > 0);
+
+            match (producers.next(), producers.next()) {
+                (Some((_, resources)), None) => resources.set_resource_count(resource, available),
+                _ => {
+                    for (_, resources) in production.iter_mut() {
+                        resources.set_resource_count(resource, 0);
+                    }
+                }
+            }
+
+            self.emit(GameEvent::BankShortage { resource });
+        }
+
+        for (token, resources) in production {
+            if resources.total() > 0 {
+                self.take_from_bank(token, resources);
+                self.emit(GameEvent::ResourcesProduced {
+                    player: token,
+                    resources,
+                });
+            }
+        }
+    }
+}
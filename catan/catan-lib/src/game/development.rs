@@ -0,0 +1,180 @@
+use crate::{
+    game::{
+        Game,
+        action::DevelopmentCardPlay,
+        error::{DevelopmentCardError, TurnError},
+        event::GameEvent,
+        phase::{RobberStep, TurnPhase},
+        player::OwnershipToken,
+    },
+    object::{
+        card::{DevelopmentCard, DevelopmentCardType, ResourceMap},
+        resource::ResourceType,
+    },
+};
+
+/// The number of free transports granted by a road building card.
+const FREE_TRANSPORTS: u32 = 2;
+
+impl Game {
+    pub fn development_cards_left(&self) -> usize {
+        self.development_cards.len()
+    }
+
+    pub fn can_buy_development_card(
+        &self,
+        token: OwnershipToken,
+    ) -> Result<(), DevelopmentCardError> {
+        self.check_turn(token)?;
+
+        if !matches!(self.phase, TurnPhase::Trade | TurnPhase::Build) {
+            return Err(TurnError::WrongPhase { phase: self.phase }.into());
+        }
+
+        let held = self.find_player(token).get_resources();
+        if !held.contains(&DevelopmentCard::cost()) {
+            return Err(DevelopmentCardError::InsufficientResources { held });
+        }
+
+        if self.development_cards.is_empty() {
+            return Err(DevelopmentCardError::EmptyDeck);
+        }
+
+        Ok(())
+    }
+
+    /// Buys the top card of the deck. Like building, this ends the trading phase.
+    pub fn buy_development_card(
+        &mut self,
+        token: OwnershipToken,
+    ) -> Result<(), DevelopmentCardError> {
+        self.can_buy_development_card(token)?;
+
+        let r#type = self
+            .development_cards
+            .draw()
+            .ok_or(DevelopmentCardError::EmptyDeck)?;
+
+        let turn_number = self.turn_number;
+        self.return_to_bank(token, DevelopmentCard::cost());
+        self.find_player_mut(token)
+            .add_development_card(DevelopmentCard::new(r#type, turn_number));
+
+        self.emit(GameEvent::DevelopmentCardBought { player: token });
+        self.set_phase(TurnPhase::Build);
+        self.check_victory();
+
+        Ok(())
+    }
+
+    pub fn can_play_development_card(
+        &self,
+        token: OwnershipToken,
+        play: DevelopmentCardPlay,
+    ) -> Result<(), DevelopmentCardError> {
+        self.check_turn(token)?;
+
+        if !self.phase.can_play_development_card() {
+            return Err(TurnError::WrongPhase { phase: self.phase }.into());
+        }
+
+        let card = play.r#type();
+
+        if self.played_development_card && card != DevelopmentCardType::VictoryPoint {
+            return Err(DevelopmentCardError::AlreadyPlayedThisTurn);
+        }
+
+        if let DevelopmentCardPlay::TakeTwoResources(first, second) = play
+            && !self
+                .bank
+                .can_supply(&[(first, 1), (second, 1)].into_iter().collect())
+        {
+            return Err(DevelopmentCardError::BankShortage {
+                available: self.bank.resources(),
+            });
+        }
+
+        self.find_player(token)
+            .can_play_development_card(card, self.turn_number)
+    }
+
+    /// Plays a development card and performs its effect. Only one card may be played per turn, except for victory
+    /// point cards, which are simply revealed.
+    pub fn play_development_card(
+        &mut self,
+        token: OwnershipToken,
+        play: DevelopmentCardPlay,
+    ) -> Result<(), DevelopmentCardError> {
+        self.can_play_development_card(token, play)?;
+
+        let card = play.r#type();
+        let is_victory_point = card == DevelopmentCardType::VictoryPoint;
+
+        let turn_number = self.turn_number;
+        self.find_player_mut(token)
+            .play_development_card(card, turn_number)?;
+
+        self.played_development_card |= !is_victory_point;
+
+        self.emit(GameEvent::DevelopmentCardPlayed {
+            player: token,
+            card,
+        });
+
+        match play {
+            DevelopmentCardPlay::MoveRobber => {
+                self.update_largest_army(token);
+                self.resume_phase = self.phase;
+                self.set_phase(TurnPhase::Robber(RobberStep::Move));
+            }
+            DevelopmentCardPlay::TakeTwoResources(first, second) => {
+                let resources = [(first, 1), (second, 1)].into_iter().collect();
+                self.take_from_bank(token, resources);
+                self.emit(GameEvent::ResourcesGained {
+                    player: token,
+                    resources,
+                });
+            }
+            DevelopmentCardPlay::Monopoly(resource) => self.monopolize(token, resource),
+            DevelopmentCardPlay::VictoryPoint => {}
+            DevelopmentCardPlay::BuildRoads => {
+                // Only as many transports as can actually be placed are granted, and without anywhere to place one
+                // the card is simply spent, rather than leaving the player stuck in a phase they cannot finish.
+                let remaining = self.free_transports(token, FREE_TRANSPORTS);
+
+                if remaining > 0 {
+                    self.resume_phase = self.phase;
+                    self.set_phase(TurnPhase::RoadBuilding {
+                        remaining: remaining as u8,
+                    });
+                }
+            }
+        }
+
+        self.check_victory();
+
+        Ok(())
+    }
+
+    /// Takes every card of the resource from all other players.
+    fn monopolize(&mut self, token: OwnershipToken, resource: ResourceType) {
+        let victims: Vec<(OwnershipToken, u32)> = self
+            .players
+            .iter()
+            .filter(|p| p.token() != token)
+            .map(|p| (p.token(), p.count_resource(resource)))
+            .filter(|(_, count)| *count > 0)
+            .collect();
+
+        for (victim, count) in victims {
+            self.find_player_mut(victim).sub_resource(resource, count);
+            self.find_player_mut(token).add_resource(resource, count);
+
+            self.emit(GameEvent::ResourcesMonopolized {
+                player: token,
+                victim,
+                resources: ResourceMap::empty().with_resource(resource, count),
+            });
+        }
+    }
+}
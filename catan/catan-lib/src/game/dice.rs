@@ -1,8 +1,9 @@
 use rand::prelude::*;
+use serde::{Deserialize, Serialize};
 
 use crate::game::GameRng;
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Dice([u8; 2]);
 
 impl Dice {
@@ -2,12 +2,17 @@ use std::iter;
 
 use hexgrid::hex::{iterators::spiral::HexSpiral, position::HexPosition};
 use rand::prelude::*;
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
 use wasm_bindgen::prelude::wasm_bindgen;
 
 use crate::{
-    game::GameRng,
+    game::{GameRng, phase::SetupRound},
     object::{
         TileData,
+        card::{
+            DevelopmentCardDeck, DevelopmentCardDistribution, DevelopmentCardType, ResourceMap,
+        },
         resource::{ResourceDeck, ResourceDistribution, ResourceType},
         structure::OwnedStructures,
         trade::{TradeDistribution, TradePort, TradePortDeck, TradeType},
@@ -26,11 +31,35 @@ const TRADE_GAP_BASE: [u32; 9] = [0, 1, 2, 1, 1, 2, 1, 1, 2];
 
 const TRADE_GAP_EXP: [u32; 11] = [0, 1, 1, 1, 1, 1, 1, 1, 3, 1, 2];
 
+/// Which of the settlements placed during setup hand out one card for each neighboring resource hex.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Tsify, Serialize, Deserialize)]
+pub enum StartingResources {
+    None,
+    FirstSettlement,
+    SecondSettlement,
+    BothSettlements,
+}
+
+impl StartingResources {
+    pub fn is_granted_in(&self, round: SetupRound) -> bool {
+        matches!(
+            (self, round),
+            (StartingResources::FirstSettlement, SetupRound::First)
+                | (StartingResources::SecondSettlement, SetupRound::Second)
+                | (StartingResources::BothSettlements, _)
+        )
+    }
+}
+
 pub trait GameEdition {
     fn get_tiles(&self, rng: &mut GameRng)
     -> impl Iterator<Item = (HexPosition, TileData)> + Clone;
     fn get_trades(&self, rng: &mut GameRng) -> impl Iterator<Item = TradePort>;
     fn get_start_structures(&self) -> OwnedStructures;
+    fn get_development_cards(&self, rng: &mut GameRng) -> DevelopmentCardDeck;
+    fn get_victory_point_target(&self) -> u32;
+    fn get_bank_resources(&self) -> ResourceMap;
+    fn get_starting_resources(&self) -> StartingResources;
 }
 
 pub struct BaseEdition;
@@ -78,6 +107,31 @@ impl GameEdition for BaseEdition {
     fn get_start_structures(&self) -> OwnedStructures {
         OwnedStructures::new(5, 4, 15, 0)
     }
+
+    fn get_development_cards(&self, rng: &mut GameRng) -> DevelopmentCardDeck {
+        DevelopmentCardDeck::new(
+            DevelopmentCardDistribution::new([
+                (DevelopmentCardType::MoveRobber, 14),
+                (DevelopmentCardType::TakeTwoResources, 2),
+                (DevelopmentCardType::Monopoly, 2),
+                (DevelopmentCardType::VictoryPoint, 5),
+                (DevelopmentCardType::BuildRoads, 2),
+            ]),
+            rng,
+        )
+    }
+
+    fn get_victory_point_target(&self) -> u32 {
+        10
+    }
+
+    fn get_bank_resources(&self) -> ResourceMap {
+        ResourceMap::new(19, 19, 19, 19, 19)
+    }
+
+    fn get_starting_resources(&self) -> StartingResources {
+        StartingResources::SecondSettlement
+    }
 }
 
 pub struct ExpansionEdition;
@@ -125,6 +179,31 @@ impl GameEdition for ExpansionEdition {
     fn get_start_structures(&self) -> OwnedStructures {
         OwnedStructures::new(5, 4, 15, 0)
     }
+
+    fn get_development_cards(&self, rng: &mut GameRng) -> DevelopmentCardDeck {
+        DevelopmentCardDeck::new(
+            DevelopmentCardDistribution::new([
+                (DevelopmentCardType::MoveRobber, 20),
+                (DevelopmentCardType::TakeTwoResources, 3),
+                (DevelopmentCardType::Monopoly, 3),
+                (DevelopmentCardType::VictoryPoint, 5),
+                (DevelopmentCardType::BuildRoads, 3),
+            ]),
+            rng,
+        )
+    }
+
+    fn get_victory_point_target(&self) -> u32 {
+        12
+    }
+
+    fn get_bank_resources(&self) -> ResourceMap {
+        ResourceMap::new(24, 24, 24, 24, 24)
+    }
+
+    fn get_starting_resources(&self) -> StartingResources {
+        StartingResources::SecondSettlement
+    }
 }
 
 #[wasm_bindgen]
@@ -136,6 +215,10 @@ pub struct CustomEdition {
     trade_distr: TradeDistribution,
     trade_gaps: Vec<u32>,
     owned_structures: OwnedStructures,
+    development_distr: DevelopmentCardDistribution,
+    victory_point_target: u32,
+    bank_resources: ResourceMap,
+    starting_resources: StartingResources,
 }
 
 impl CustomEdition {
@@ -177,6 +260,22 @@ impl GameEdition for CustomEdition {
     fn get_start_structures(&self) -> OwnedStructures {
         self.owned_structures
     }
+
+    fn get_development_cards(&self, rng: &mut GameRng) -> DevelopmentCardDeck {
+        DevelopmentCardDeck::new(self.development_distr.clone(), rng)
+    }
+
+    fn get_victory_point_target(&self) -> u32 {
+        self.victory_point_target
+    }
+
+    fn get_bank_resources(&self) -> ResourceMap {
+        self.bank_resources
+    }
+
+    fn get_starting_resources(&self) -> StartingResources {
+        self.starting_resources
+    }
 }
 
 #[wasm_bindgen]
@@ -188,6 +287,10 @@ pub struct CustomEditionBuilder {
     trade_distr: TradeDistribution,
     trade_gaps: Vec<u32>,
     owned_structures: OwnedStructures,
+    development_distr: DevelopmentCardDistribution,
+    victory_point_target: u32,
+    bank_resources: ResourceMap,
+    starting_resources: StartingResources,
 }
 
 impl CustomEditionBuilder {
@@ -200,6 +303,10 @@ impl CustomEditionBuilder {
             trade_distr: self.trade_distr,
             trade_gaps: self.trade_gaps,
             owned_structures: self.owned_structures,
+            development_distr: self.development_distr,
+            victory_point_target: self.victory_point_target,
+            bank_resources: self.bank_resources,
+            starting_resources: self.starting_resources,
         }
     }
 
@@ -212,6 +319,10 @@ impl CustomEditionBuilder {
             trade_distr: Self::default_trade_distribution(shortest, longest),
             trade_gaps: Self::default_trade_gaps(shortest, longest),
             owned_structures: Self::default_owned_structures(),
+            development_distr: Self::default_development_card_distribution(),
+            victory_point_target: Self::default_victory_point_target(shortest, longest),
+            bank_resources: Self::default_bank_resources(shortest, longest),
+            starting_resources: StartingResources::SecondSettlement,
         }
     }
 
@@ -243,6 +354,32 @@ impl CustomEditionBuilder {
         self
     }
 
+    pub fn with_development_card_distribution(
+        mut self,
+        distr: DevelopmentCardDistribution,
+    ) -> CustomEditionBuilder {
+        self.development_distr = distr;
+        self
+    }
+
+    pub fn with_victory_point_target(mut self, target: u32) -> CustomEditionBuilder {
+        self.victory_point_target = target;
+        self
+    }
+
+    pub fn with_bank_resources(mut self, resources: ResourceMap) -> CustomEditionBuilder {
+        self.bank_resources = resources;
+        self
+    }
+
+    pub fn with_starting_resources(
+        mut self,
+        starting_resources: StartingResources,
+    ) -> CustomEditionBuilder {
+        self.starting_resources = starting_resources;
+        self
+    }
+
     fn size(shortest: u32, longest: u32) -> usize {
         ((longest - 1) * longest - (shortest - 1) * shortest + longest) as usize
     }
@@ -321,4 +458,32 @@ impl CustomEditionBuilder {
     fn default_owned_structures() -> OwnedStructures {
         OwnedStructures::new(5, 4, 15, 0)
     }
+
+    /// Maps larger than the base game's 19 hexes take longer to fill up, so they play to 12 points instead of 10.
+    fn default_victory_point_target(shortest: u32, longest: u32) -> u32 {
+        if Self::size(shortest, longest) > 19 {
+            12
+        } else {
+            10
+        }
+    }
+
+    /// Larger maps produce more, so they get the expansion's bigger bank.
+    fn default_bank_resources(shortest: u32, longest: u32) -> ResourceMap {
+        if Self::size(shortest, longest) > 19 {
+            ResourceMap::new(24, 24, 24, 24, 24)
+        } else {
+            ResourceMap::new(19, 19, 19, 19, 19)
+        }
+    }
+
+    fn default_development_card_distribution() -> DevelopmentCardDistribution {
+        DevelopmentCardDistribution::new([
+            (DevelopmentCardType::MoveRobber, 14),
+            (DevelopmentCardType::TakeTwoResources, 2),
+            (DevelopmentCardType::Monopoly, 2),
+            (DevelopmentCardType::VictoryPoint, 5),
+            (DevelopmentCardType::BuildRoads, 2),
+        ])
+    }
 }
@@ -2,32 +2,171 @@ use std::fmt::Debug;
 
 use thiserror::Error;
 
+use hexgrid::{
+    corner::position::CornerPosition, edge::position::EdgePosition, hex::position::HexPosition,
+};
+
 use crate::{
-    game::player::OwnershipToken,
-    object::{card::ResourceCard, structure::StructureType},
+    game::{phase::TurnPhase, player::OwnershipToken, trade::TradeOfferId},
+    object::{
+        card::{DevelopmentCard, DevelopmentCardType, ResourceCard, ResourceMap},
+        resource::ResourceType,
+        structure::StructureType,
+    },
 };
 
-#[derive(Debug, Clone)]
+#[derive(Error, Debug, Clone)]
 pub enum BuildError {
+    #[error(
+        "The {structure:?} structure requires {:?} resources, but the following are insufficient: {insufficient_resources:?}",
+        structure.cost()
+    )]
     InsufficientResources {
         structure: StructureType,
         insufficient_resources: Vec<ResourceCard>,
     },
+    #[error("This position is outside of the board!")]
+    OutOfBounds,
+    #[error("A structure already exists at this position!")]
     StructureAlreadyExists,
+    #[error("Buildings cannot be placed next to an existing building!")]
     BuildingIsTooCloseToExisting,
+    #[error("This building would cut off another player's road!")]
     BuildingCutsOffRoad,
+    #[error("Buildings must be connected to one of your roads!")]
     BuildingHasNoRoad,
+    #[error("Buildings must neighbor land!")]
     BuildingIsOnWater,
+    #[error("Cities must be upgraded from a settlement!")]
     CityRequiresSettlement,
+    #[error("Cities can only be upgraded from your own settlements!")]
     CityUpgradeOwnerMismatch,
+    #[error("The player with {token:?} token has no {structure:?} structures left!")]
     NoStructures {
         token: OwnershipToken,
         structure: StructureType,
     },
+    #[error("Transports must be connected to your other structures!")]
     TransportMustBeContiguous,
+    #[error("Transports cannot continue through another player's building!")]
     TransportInterruptsBuilding,
+    #[error("Roads must neighbor land!")]
     RoadMustNeighborLand,
+    #[error("Boats must neighbor water!")]
     BoatMustNeighborWater,
+    #[error("The setup transport must touch the building that was just placed!")]
+    SetupTransportMustTouchBuilding,
+    #[error(transparent)]
+    Turn(#[from] TurnError),
+}
+
+#[derive(Error, Debug, Clone, Copy)]
+pub enum TurnError {
+    #[error("It is the turn of the player with {current:?}, not {attempted:?}!")]
+    NotPlayersTurn {
+        current: OwnershipToken,
+        attempted: OwnershipToken,
+    },
+    #[error("This action cannot be taken during the {phase:?} phase!")]
+    WrongPhase { phase: TurnPhase },
+}
+
+#[derive(Error, Debug, Clone, Copy)]
+pub enum RobberError {
+    #[error("The player with {token:?} token does not need to discard!")]
+    NoDiscardRequired { token: OwnershipToken },
+    #[error("{expected} cards must be discarded, but {found} were chosen!")]
+    WrongDiscardCount { expected: u32, found: u32 },
+    #[error("Cannot discard {discard:?}, as only {held:?} are held!")]
+    InsufficientCards {
+        discard: ResourceMap,
+        held: ResourceMap,
+    },
+    #[error("The robber must be moved to a different hex!")]
+    SameHex,
+    #[error("The robber cannot be moved onto water at {position}!")]
+    NotLand { position: HexPosition },
+    #[error(
+        "The player with {victim:?} token has no building next to the robber, or no cards to steal!"
+    )]
+    InvalidVictim { victim: OwnershipToken },
+    #[error(transparent)]
+    Turn(#[from] TurnError),
+}
+
+#[derive(Error, Debug, Clone, Copy)]
+pub enum DevelopmentCardError {
+    #[error("There are no development cards left to buy!")]
+    EmptyDeck,
+    #[error(
+        "A development card costs {:?}, but only {held:?} are held!",
+        DevelopmentCard::cost()
+    )]
+    InsufficientResources { held: ResourceMap },
+    #[error("There is no unplayed {0:?} card to play!")]
+    NoCard(DevelopmentCardType),
+    #[error("Development cards cannot be played on the turn they were bought!")]
+    BoughtThisTurn,
+    #[error("Only one development card may be played per turn!")]
+    AlreadyPlayedThisTurn,
+    #[error("The bank only holds {available:?}!")]
+    BankShortage { available: ResourceMap },
+    #[error(transparent)]
+    Turn(#[from] TurnError),
+}
+
+#[derive(Error, Debug, Clone, Copy)]
+pub enum TradeError {
+    #[error("A trade must exchange resources in both directions!")]
+    OneSided,
+    #[error("Cannot trade with the player with {partner:?} token!")]
+    InvalidPartner { partner: OwnershipToken },
+    #[error("There is no open trade offer {id:?} for the player with {player:?} token!")]
+    NoOffer {
+        id: TradeOfferId,
+        player: OwnershipToken,
+    },
+    #[error("{resource:?} can only be traded with the bank in multiples of {rate}!")]
+    InvalidRate { resource: ResourceType, rate: u32 },
+    #[error("{0:?} cannot be traded for itself!")]
+    SameResource(ResourceType),
+    #[error(
+        "The cards given to the bank pay for {paid_for} cards, but {requested} were requested!"
+    )]
+    UnbalancedBankTrade { paid_for: u32, requested: u32 },
+    #[error(
+        "The player with {player:?} token only holds {held:?}, which does not cover this trade!"
+    )]
+    InsufficientResources {
+        player: OwnershipToken,
+        held: ResourceMap,
+    },
+    #[error("The bank only holds {available:?}!")]
+    BankShortage { available: ResourceMap },
+    #[error(transparent)]
+    Turn(#[from] TurnError),
+}
+
+#[derive(Error, Debug, Clone)]
+pub enum ActionError {
+    #[error("The corner {0} is not on the board!")]
+    CornerNotOnBoard(CornerPosition),
+    #[error("The edge {0} is not on the board!")]
+    EdgeNotOnBoard(EdgePosition),
+    #[error("The hex {0} is not on the board!")]
+    HexNotOnBoard(HexPosition),
+    #[error("The cards in {0:?} add up to more than can be counted!")]
+    TooManyResources(ResourceMap),
+    #[error(transparent)]
+    Turn(#[from] TurnError),
+    #[error(transparent)]
+    Build(#[from] BuildError),
+    #[error(transparent)]
+    Robber(#[from] RobberError),
+    #[error(transparent)]
+    DevelopmentCard(#[from] DevelopmentCardError),
+    #[error(transparent)]
+    Trade(#[from] TradeError),
 }
 
 #[derive(Error, Debug, Clone, Copy)]
@@ -35,34 +174,3 @@ pub enum GameError {
     #[error("Not enough players!")]
     InsufficientPlayerCount,
 }
-
-// impl fmt::Display for BuildError {
-//     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-//         match self {
-//             BuildError::InsufficientResources {
-//                 structure,
-//                 insufficient_resources,
-//             } => {
-//                 write!(
-//                     f,
-//                     "The {:?} structure requires {:?} resources, but the following are insufficient: {:?}",
-//                     structure,
-//                     structure.cost(),
-//                     insufficient_resources
-//                 )
-//             }
-//             BuildError::StructureAlreadyExists => write!(f, ""),
-//             BuildError::BuildingIsTooCloseToExisting => write!(f, ""),
-//             BuildError::BuildingCutsOffRoad => write!(f, ""),
-//             BuildError::BuildingHasNoRoad => write!(f, ""),
-//             BuildError::CityRequiresSettlement => write!(f, ""),
-//             BuildError::CityUpgradeTokenMismatch => write!(f, ""),
-//             BuildError::NoStructures { token, structure } => write!(
-//                 f,
-//                 "The player with {:?} token has no {:?} structures left!",
-//                 token, structure
-//             ),
-//             BuildError::RoadsMustBeContiguous => write!(f, "")
-//         }
-//     }
-// }
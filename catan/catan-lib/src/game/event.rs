@@ -0,0 +1,213 @@
+use hexgrid::{
+    corner::position::CornerPosition, edge::position::EdgePosition, hex::position::HexPosition,
+};
+use std::{cell::RefCell, collections::VecDeque, fmt, rc::Rc};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    game::{dice::Dice, phase::TurnPhase, player::OwnershipToken, trade::TradeOfferId},
+    object::{
+        card::{DevelopmentCardType, ResourceMap},
+        resource::ResourceType,
+        structure::{building::Building, transport::Transport},
+    },
+};
+
+/// Describes a single change to the state of a game, in the order it happened.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GameEvent {
+    DiceRolled {
+        player: OwnershipToken,
+        dice: Dice,
+    },
+    ResourcesProduced {
+        player: OwnershipToken,
+        resources: ResourceMap,
+    },
+    /// The bank could not supply every player with the resource they produced, so it was withheld.
+    BankShortage {
+        resource: ResourceType,
+    },
+    BuildingPlaced {
+        building: Building,
+        position: CornerPosition,
+    },
+    TransportPlaced {
+        transport: Transport,
+        position: EdgePosition,
+    },
+    CardsDiscarded {
+        player: OwnershipToken,
+        resources: ResourceMap,
+    },
+    /// `CardsDiscarded` as everyone but the discarding player sees it, see `GameEvent::redacted_for`.
+    HiddenCardsDiscarded {
+        player: OwnershipToken,
+        count: u32,
+    },
+    RobberMoved {
+        player: OwnershipToken,
+        position: HexPosition,
+    },
+    CardStolen {
+        thief: OwnershipToken,
+        victim: OwnershipToken,
+        resource: ResourceType,
+    },
+    /// `CardStolen` as everyone but the thief and the victim sees it, see `GameEvent::redacted_for`.
+    HiddenCardStolen {
+        thief: OwnershipToken,
+        victim: OwnershipToken,
+    },
+    DevelopmentCardBought {
+        player: OwnershipToken,
+    },
+    DevelopmentCardPlayed {
+        player: OwnershipToken,
+        card: DevelopmentCardType,
+    },
+    /// Resources taken from the bank by a development card.
+    ResourcesGained {
+        player: OwnershipToken,
+        resources: ResourceMap,
+    },
+    /// Resources taken from another player by a monopoly card.
+    ResourcesMonopolized {
+        player: OwnershipToken,
+        victim: OwnershipToken,
+        resources: ResourceMap,
+    },
+    /// The Longest Road card changed hands, or was set aside if there is no player.
+    LongestRoadChanged {
+        player: Option<OwnershipToken>,
+    },
+    LargestArmyChanged {
+        player: OwnershipToken,
+    },
+    /// `give` and `receive` are from the point of view of the proposing player.
+    TradeProposed {
+        offer: TradeOfferId,
+        from: OwnershipToken,
+        to: Option<OwnershipToken>,
+        give: ResourceMap,
+        receive: ResourceMap,
+    },
+    TradeRejected {
+        offer: TradeOfferId,
+        player: OwnershipToken,
+    },
+    /// The offer was withdrawn, rejected by everyone or dropped at the end of the trading phase.
+    TradeClosed {
+        offer: TradeOfferId,
+    },
+    TradeCompleted {
+        offer: TradeOfferId,
+        from: OwnershipToken,
+        to: OwnershipToken,
+        give: ResourceMap,
+        receive: ResourceMap,
+    },
+    BankTradeCompleted {
+        player: OwnershipToken,
+        give: ResourceMap,
+        receive: ResourceMap,
+    },
+    PhaseChanged {
+        phase: TurnPhase,
+    },
+    TurnEnded {
+        player: OwnershipToken,
+    },
+    GameWon {
+        player: OwnershipToken,
+    },
+}
+
+impl GameEvent {
+    /// The event as the given player is allowed to see it. Games only emit the full events, so anything shown to a
+    /// player should go through this, like `Game::view_for`: stolen cards are only shown to the thief and the victim,
+    /// and discarded cards only to the player who discarded them, while everyone else just learns how many.
+    pub fn redacted_for(self, viewer: OwnershipToken) -> GameEvent {
+        match self {
+            GameEvent::CardStolen { thief, victim, .. } if viewer != thief && viewer != victim => {
+                GameEvent::HiddenCardStolen { thief, victim }
+            }
+            GameEvent::CardsDiscarded { player, resources } if viewer != player => {
+                GameEvent::HiddenCardsDiscarded {
+                    player,
+                    count: resources.total(),
+                }
+            }
+            event => event,
+        }
+    }
+}
+
+/// Gets told about every event of a game as it happens, for example to update a user interface or to write a log.
+pub trait EventSubscriber {
+    fn on_event(&mut self, event: &GameEvent);
+}
+
+/// The subscribers of a game. They are not part of its state, so saved games and copies start out without any.
+#[derive(Default)]
+pub struct EventSubscribers(Vec<Box<dyn EventSubscriber>>);
+
+impl EventSubscribers {
+    pub(super) fn push(&mut self, subscriber: Box<dyn EventSubscriber>) {
+        self.0.push(subscriber)
+    }
+
+    pub(super) fn notify(&mut self, event: &GameEvent) {
+        for subscriber in &mut self.0 {
+            subscriber.on_event(event);
+        }
+    }
+}
+
+impl fmt::Debug for EventSubscribers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "EventSubscribers({})", self.0.len())
+    }
+}
+
+/// Collects events until they are drained. Clones share the same queue, so one can be subscribed to the game while
+/// another is kept to read from.
+#[derive(Debug, Clone, Default)]
+pub struct EventQueue {
+    events: Rc<RefCell<VecDeque<GameEvent>>>,
+}
+
+impl EventQueue {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Takes every event collected so far, oldest first.
+    pub fn drain(&self) -> Vec<GameEvent> {
+        self.events.borrow_mut().drain(..).collect()
+    }
+
+    /// Like `drain`, but with every event redacted for the given player.
+    pub fn drain_for(&self, viewer: OwnershipToken) -> Vec<GameEvent> {
+        self.events
+            .borrow_mut()
+            .drain(..)
+            .map(|event| event.redacted_for(viewer))
+            .collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.events.borrow().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.events.borrow().is_empty()
+    }
+}
+
+impl EventSubscriber for EventQueue {
+    fn on_event(&mut self, event: &GameEvent) {
+        self.events.borrow_mut().push_back(*event);
+    }
+}
@@ -1,10 +1,15 @@
-use crate::object::{
-    card::{DevelopmentCard, DevelopmentCardType, ResourceCard, ResourceMap},
-    resource::ResourceType,
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    game::error::DevelopmentCardError,
+    object::{
+        card::{DevelopmentCard, DevelopmentCardType, ResourceCard, ResourceMap},
+        resource::ResourceType,
+    },
 };
 
 /// Controls and handles all of the cards and unplayed structures.
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Hand {
     resource_cards: ResourceMap,
     development_cards: Vec<DevelopmentCard>,
@@ -31,15 +36,53 @@ impl Hand {
         self.development_cards.push(card)
     }
 
-    /// Searches for the first card of the specified type that has not been played already.
-    pub fn play_development_card(&mut self, r#type: DevelopmentCardType) {
-        if let Some(card) = self
+    /// Checks that there is a card of the specified type that has not been played already, and was not bought on the
+    /// current turn. Victory point cards may be revealed on the turn they were bought.
+    pub fn can_play_development_card(
+        &self,
+        r#type: DevelopmentCardType,
+        turn_number: usize,
+    ) -> Result<(), DevelopmentCardError> {
+        self.playable_development_card(r#type, turn_number)
+            .map(|_| ())
+    }
+
+    /// Plays the first card of the specified type which passes `can_play_development_card`.
+    pub fn play_development_card(
+        &mut self,
+        r#type: DevelopmentCardType,
+        turn_number: usize,
+    ) -> Result<(), DevelopmentCardError> {
+        let index = self.playable_development_card(r#type, turn_number)?;
+        self.development_cards[index].play();
+
+        Ok(())
+    }
+
+    fn playable_development_card(
+        &self,
+        r#type: DevelopmentCardType,
+        turn_number: usize,
+    ) -> Result<usize, DevelopmentCardError> {
+        let mut unplayed = self
             .development_cards
-            .iter_mut()
-            .find(|c| c.get_type() == r#type && c.is_played() == false)
-        {
-            card.play()
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| c.get_type() == r#type && !c.is_played())
+            .peekable();
+
+        if unplayed.peek().is_none() {
+            return Err(DevelopmentCardError::NoCard(r#type));
         }
+
+        unplayed
+            .find(|(_, c)| c.bought_on() != turn_number || c.is_victory_point())
+            .map(|(index, _)| index)
+            .ok_or(DevelopmentCardError::BoughtThisTurn)
+    }
+
+    pub fn development_cards(&self) -> impl Iterator<Item = &DevelopmentCard> {
+        self.development_cards.iter()
     }
 
     /// Counts the number of victory points from all played VP Development cards.
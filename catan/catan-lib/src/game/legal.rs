@@ -0,0 +1,322 @@
+use hexgrid::{corner::position::CornerPosition, edge::position::EdgePosition};
+
+use crate::{
+    game::{
+        Game,
+        action::{Action, DevelopmentCardPlay},
+        phase::{RobberStep, SetupStep, TurnPhase},
+        player::OwnershipToken,
+    },
+    object::{
+        card::{DEVELOPMENT_CARDS, DevelopmentCardType, ResourceMap},
+        resource::{RESOURCES, Resources},
+        structure::{
+            building::{Building, BuildingType},
+            transport::{Transport, TransportType},
+        },
+    },
+};
+
+impl Game {
+    /// Lists every action the given player could successfully apply right now.
+    ///
+    /// Trades between players are left out, since any amounts could be offered. Responses to open offers are included,
+    /// except for counter offers. Bank trades are listed as one card at a time. The list is never empty for the player
+    /// the game is waiting on, until the game is over.
+    pub fn legal_actions(&self, token: OwnershipToken) -> Vec<Action> {
+        let mut actions = Vec::new();
+
+        match self.phase {
+            TurnPhase::GameOver { .. } => {}
+            TurnPhase::Setup { step, .. } if token == self.current_turn => match step {
+                SetupStep::Building => self.legal_settlements(token, &mut actions),
+                SetupStep::Transport(corner) => {
+                    let edges = self.board.neighboring_edges_for_corner(corner).collect();
+                    self.legal_transports(token, edges, &mut actions);
+                }
+            },
+            TurnPhase::Setup { .. } => {}
+            TurnPhase::Robber(RobberStep::Discard) => self.legal_discards(token, &mut actions),
+            TurnPhase::Robber(RobberStep::Move) => actions.extend(
+                self.board
+                    .hex_positions()
+                    .filter(|p| self.can_move_robber(token, *p).is_ok())
+                    .map(Action::MoveRobber),
+            ),
+            TurnPhase::Robber(RobberStep::Steal(position)) if token == self.current_turn => {
+                actions.extend(self.robber_victims(token, position).map(Action::Steal))
+            }
+            TurnPhase::Robber(RobberStep::Steal(_)) => {}
+            TurnPhase::Roll if token == self.current_turn => actions.push(Action::RollDice),
+            TurnPhase::Roll => {}
+            TurnPhase::Trade | TurnPhase::Build | TurnPhase::RoadBuilding { .. } => {
+                if token == self.current_turn {
+                    self.legal_builds(token, &mut actions);
+                }
+                self.legal_trades(token, &mut actions);
+            }
+        }
+
+        self.legal_development_card_plays(token, &mut actions);
+
+        if token == self.current_turn && self.phase.can_end_turn() {
+            actions.push(Action::EndTurn);
+        }
+
+        actions
+    }
+
+    fn legal_builds(&self, token: OwnershipToken, actions: &mut Vec<Action>) {
+        self.legal_settlements(token, actions);
+
+        actions.extend(
+            self.buildings
+                .iter()
+                .filter(|(b, _, _)| b.owner() == token && b.r#type() == BuildingType::Settlement)
+                .map(|(_, position, _)| *position)
+                .filter(|p| self.can_afford_building(Building::new(BuildingType::City, token), *p))
+                .map(Action::BuildCity),
+        );
+
+        self.legal_transports(token, self.network_edges(token), actions);
+
+        if self.can_buy_development_card(token).is_ok() {
+            actions.push(Action::BuyDevelopmentCard);
+        }
+    }
+
+    /// During setup any corner may be settled, otherwise only those at the end of the player's transports.
+    fn legal_settlements(&self, token: OwnershipToken, actions: &mut Vec<Action>) {
+        let settlement = Building::new(BuildingType::Settlement, token);
+
+        let corners: Vec<CornerPosition> = if self.phase.is_setup() {
+            self.board.corner_positions().collect()
+        } else {
+            self.network_corners(token)
+        };
+
+        actions.extend(
+            corners
+                .into_iter()
+                .filter(|p| self.can_afford_building(settlement, *p))
+                .map(Action::BuildSettlement),
+        );
+    }
+
+    fn legal_transports(
+        &self,
+        token: OwnershipToken,
+        edges: Vec<EdgePosition>,
+        actions: &mut Vec<Action>,
+    ) {
+        for edge in edges {
+            if self.can_afford_transport(Transport::new(TransportType::Road, token), edge) {
+                actions.push(Action::BuildRoad(edge));
+            }
+            if self.can_afford_transport(Transport::new(TransportType::Boat, token), edge) {
+                actions.push(Action::BuildBoat(edge));
+            }
+        }
+    }
+
+    /// How many transports the player could place for free one after the other, up to `limit`. Every placement is
+    /// tried on a copy of the game, since each one can open up or block the next.
+    pub(super) fn free_transports(&self, token: OwnershipToken, limit: u32) -> u32 {
+        if limit == 0 {
+            return 0;
+        }
+
+        let player = self.find_player(token);
+        let edges = self.network_edges(token);
+        let placements = [TransportType::Road, TransportType::Boat]
+            .map(|r#type| Transport::new(r#type, token))
+            .into_iter()
+            .filter(|t| player.count_structure((*t).into()) > 0)
+            .flat_map(|t| edges.iter().map(move |e| (t, *e)))
+            .filter(|(t, e)| self.check_transport_position(*t, *e).is_ok());
+
+        let mut most = 0;
+        for (transport, edge) in placements {
+            if limit == 1 {
+                return 1;
+            }
+
+            let mut game = self.clone();
+            if game.board.set_transport(transport, edge).is_err()
+                || game
+                    .find_player_mut(token)
+                    .play_structure(transport.into(), true)
+                    .is_err()
+            {
+                continue;
+            }
+            game.transports.push((transport, edge));
+
+            most = most.max(1 + game.free_transports(token, limit - 1));
+            if most == limit {
+                break;
+            }
+        }
+
+        most
+    }
+
+    /// Transports have to connect to the player's own network, so only edges touching it are candidates.
+    fn network_edges(&self, token: OwnershipToken) -> Vec<EdgePosition> {
+        let mut edges = Vec::new();
+        for corner in self.network_corners(token) {
+            for edge in self.board.neighboring_edges_for_corner(corner) {
+                if !edges.contains(&edge) {
+                    edges.push(edge);
+                }
+            }
+        }
+
+        edges
+    }
+
+    /// Every corner touched by one of the player's transports, without duplicates.
+    fn network_corners(&self, token: OwnershipToken) -> Vec<CornerPosition> {
+        let mut corners = Vec::new();
+
+        for (_, position) in self.transports.iter().filter(|(t, _)| t.owner() == token) {
+            for corner in self.board.neighboring_corners_for_edge(*position) {
+                if !corners.contains(&corner) {
+                    corners.push(corner);
+                }
+            }
+        }
+
+        corners
+    }
+
+    fn can_afford_building(&self, building: Building, position: CornerPosition) -> bool {
+        self.can_play_building(building, position).is_ok()
+            && self
+                .find_player(building.owner())
+                .try_play_structure(building.into(), self.phase.is_free_placement())
+                .is_ok()
+    }
+
+    fn can_afford_transport(&self, transport: Transport, position: EdgePosition) -> bool {
+        self.can_play_transport(transport, position).is_ok()
+            && self
+                .find_player(transport.owner())
+                .try_play_structure(transport.into(), self.phase.is_free_placement())
+                .is_ok()
+    }
+
+    /// Every distinct selection of cards of the required size from the player's hand.
+    fn legal_discards(&self, token: OwnershipToken, actions: &mut Vec<Action>) {
+        let Some(count) = self.pending_discard(token) else {
+            return;
+        };
+
+        fn choose(
+            held: ResourceMap,
+            index: usize,
+            remaining: u32,
+            discard: ResourceMap,
+            actions: &mut Vec<Action>,
+        ) {
+            let Some(resource) = RESOURCES.get(index) else {
+                if remaining == 0 {
+                    actions.push(Action::Discard(discard));
+                }
+                return;
+            };
+
+            for n in 0..=held.get(*resource).get_count().min(remaining) {
+                choose(
+                    held,
+                    index + 1,
+                    remaining - n,
+                    discard.with_resource(*resource, n),
+                    actions,
+                );
+            }
+        }
+
+        choose(
+            self.find_player(token).get_resources(),
+            0,
+            count,
+            ResourceMap::empty(),
+            actions,
+        );
+    }
+
+    fn legal_trades(&self, token: OwnershipToken, actions: &mut Vec<Action>) {
+        if self.phase != TurnPhase::Trade {
+            return;
+        }
+
+        let held = self.find_player(token).get_resources();
+
+        for offer in &self.trade_offers {
+            if offer.is_addressed_to(token) {
+                if held.contains(&offer.receive())
+                    && self
+                        .find_player(offer.from())
+                        .get_resources()
+                        .contains(&offer.give())
+                {
+                    actions.push(Action::AcceptTrade(offer.id()));
+                }
+                actions.push(Action::RejectTrade(offer.id()));
+            } else if offer.from() == token {
+                actions.push(Action::WithdrawTrade(offer.id()));
+            }
+        }
+
+        if token != self.current_turn {
+            return;
+        }
+
+        for give in Resources::new() {
+            let rate = self.trade_rate(token, give);
+            if held.get(give).get_count() < rate {
+                continue;
+            }
+
+            for receive in Resources::new().into_iter().filter(|r| *r != give) {
+                let receive = ResourceMap::empty().with_resource(receive, 1);
+                if self.bank.can_supply(&receive) {
+                    actions.push(Action::TradeWithBank {
+                        give: ResourceMap::empty().with_resource(give, rate),
+                        receive,
+                    });
+                }
+            }
+        }
+    }
+
+    fn legal_development_card_plays(&self, token: OwnershipToken, actions: &mut Vec<Action>) {
+        for card in DEVELOPMENT_CARDS {
+            let plays = match card {
+                DevelopmentCardType::MoveRobber => vec![DevelopmentCardPlay::MoveRobber],
+                DevelopmentCardType::TakeTwoResources => RESOURCES
+                    .iter()
+                    .enumerate()
+                    .flat_map(|(i, first)| {
+                        RESOURCES[i..]
+                            .iter()
+                            .map(|second| DevelopmentCardPlay::TakeTwoResources(*first, *second))
+                    })
+                    .collect(),
+                DevelopmentCardType::Monopoly => {
+                    RESOURCES.map(DevelopmentCardPlay::Monopoly).to_vec()
+                }
+                DevelopmentCardType::VictoryPoint => vec![DevelopmentCardPlay::VictoryPoint],
+                DevelopmentCardType::BuildRoads => vec![DevelopmentCardPlay::BuildRoads],
+            };
+
+            actions.extend(
+                plays
+                    .into_iter()
+                    .filter(|p| self.can_play_development_card(token, *p).is_ok())
+                    .map(Action::PlayDevelopmentCard),
+            );
+        }
+    }
+}
@@ -1,33 +1,52 @@
+pub mod action;
+pub mod bank;
+pub mod development;
 pub mod dice;
 pub mod edition;
 pub mod error;
+pub mod event;
 pub mod hand;
+pub mod legal;
+pub mod phase;
 pub mod player;
-pub mod transport_segment;
+pub mod replay;
+pub mod robber;
+pub mod save;
+pub mod special;
+pub mod trade;
+pub mod victory;
+pub mod view;
 
 #[cfg(test)]
 pub mod test;
 
-use std::{fmt::Debug, iter, num::NonZeroUsize, usize::MAX};
+use std::{fmt::Debug, num::NonZeroUsize};
 
 use hexgrid::{
     corner::position::CornerPosition, edge::position::EdgePosition, hex::position::HexPosition,
 };
-use rand::rngs::ChaCha8Rng;
+use rand::{SeedableRng, rngs::ChaCha8Rng};
+use serde::{Deserialize, Serialize};
 
 use crate::{
     board::Board,
     game::{
-        edition::GameEdition,
-        error::BuildError,
+        action::Action,
+        bank::Bank,
+        dice::Dice,
+        edition::{GameEdition, StartingResources},
+        error::{ActionError, BuildError, TurnError},
+        event::{EventSubscriber, EventSubscribers, GameEvent},
+        phase::{SetupRound, SetupStep, TurnPhase},
         player::{OwnershipToken, Player},
-        transport_segment::TransportSegment,
+        trade::TradeOffer,
     },
     object::{
         TileData, TileType,
-        card::ResourceMap,
+        card::{DevelopmentCardDeck, ResourceMap},
         resource::ResourceType,
         structure::{
+            StructureType,
             building::{Building, BuildingType},
             transport::{Transport, TransportType},
         },
@@ -37,16 +56,92 @@ use crate::{
 
 pub type GameRng = ChaCha8Rng;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct Game {
     // main members
     board: Board,
     players: Vec<Player>,
     current_turn: OwnershipToken,
     turn_number: usize,
+    phase: TurnPhase,
+    #[serde(with = "save::rng_state")]
+    rng: GameRng,
+    #[serde(skip)]
+    events: Vec<GameEvent>,
+    #[serde(skip)]
+    subscribers: EventSubscribers,
+    /// Players who still have to discard after a 7 was rolled, and how many cards each.
+    discards: Vec<(OwnershipToken, u32)>,
+    /// The phase to return to once the robber or a road building card has been resolved.
+    resume_phase: TurnPhase,
+    bank: Bank,
+    development_cards: DevelopmentCardDeck,
+    played_development_card: bool,
+    longest_road: Option<OwnershipToken>,
+    largest_army: Option<OwnershipToken>,
+    victory_point_target: u32,
+    starting_resources: StartingResources,
+    /// Offers made during the current trading phase which have not been accepted, rejected or withdrawn yet.
+    trade_offers: Vec<TradeOffer>,
+    next_trade_offer: u32,
     // redundant data for ease of use
-    buildings: Vec<(Building, Vec<HexPosition>)>,
-    transports: Vec<(OwnershipToken, Option<EdgePosition>)>,
+    buildings: Vec<(Building, CornerPosition, Vec<HexPosition>)>,
+    transports: Vec<(Transport, EdgePosition)>,
+}
+
+/// Copies the whole game, including the state of its RNG, so the copy plays out exactly like the original would. This is
+/// written out since `GameRng` is not `Clone`; destructuring makes sure no new field is forgotten.
+impl Clone for Game {
+    fn clone(&self) -> Self {
+        let Self {
+            board,
+            players,
+            current_turn,
+            turn_number,
+            phase,
+            rng,
+            events,
+            subscribers: _,
+            discards,
+            resume_phase,
+            bank,
+            development_cards,
+            played_development_card,
+            longest_road,
+            largest_army,
+            victory_point_target,
+            starting_resources,
+            trade_offers,
+            next_trade_offer,
+            buildings,
+            transports,
+        } = self;
+
+        Self {
+            board: board.clone(),
+            players: players.clone(),
+            current_turn: *current_turn,
+            turn_number: *turn_number,
+            phase: *phase,
+            rng: save::rng_state::clone(rng),
+            events: events.clone(),
+            // Whoever subscribed is watching the original game, not the copy.
+            subscribers: EventSubscribers::default(),
+            discards: discards.clone(),
+            resume_phase: *resume_phase,
+            bank: bank.clone(),
+            development_cards: development_cards.clone(),
+            played_development_card: *played_development_card,
+            longest_road: *longest_road,
+            largest_army: *largest_army,
+            victory_point_target: *victory_point_target,
+            starting_resources: *starting_resources,
+            trade_offers: trade_offers.clone(),
+            next_trade_offer: *next_trade_offer,
+            buildings: buildings.clone(),
+            transports: transports.clone(),
+        }
+    }
 }
 
 impl Game {
@@ -57,21 +152,36 @@ impl Game {
             players.push(Player::new(owned_structures))
         }
 
-        let mut transports = Vec::new();
-
-        for p in players.iter() {
-            transports.push((p.token(), None));
-        }
-
         let current_turn = players.get(0).expect("Not enough players!").token();
 
+        let board = Board::new(&edition, rng);
+        let bank = Bank::new(edition.get_bank_resources());
+        let development_cards = edition.get_development_cards(rng);
+        let victory_point_target = edition.get_victory_point_target();
+        let starting_resources = edition.get_starting_resources();
+
         Self {
-            board: Board::new(edition, rng),
+            board,
             players,
             current_turn,
             turn_number: 0,
+            phase: TurnPhase::START,
+            rng: GameRng::from_rng(rng),
+            events: Vec::new(),
+            subscribers: EventSubscribers::default(),
+            discards: Vec::new(),
+            resume_phase: TurnPhase::Trade,
+            bank,
+            development_cards,
+            played_development_card: false,
+            longest_road: None,
+            largest_army: None,
+            victory_point_target,
+            starting_resources,
+            trade_offers: Vec::new(),
+            next_trade_offer: 0,
             buildings: Vec::new(),
-            transports,
+            transports: Vec::new(),
         }
     }
 
@@ -107,6 +217,10 @@ impl Game {
         self.board.trade_ports()
     }
 
+    pub fn get_robber(&self) -> HexPosition {
+        self.board.get_robber()
+    }
+
     pub fn get_player(&self, player_number: usize) -> Option<&Player> {
         self.players.get(player_number)
     }
@@ -122,26 +236,288 @@ impl Game {
             .unwrap()
     }
 
-    pub fn next_turn(&mut self) {
+    pub fn current_turn(&self) -> OwnershipToken {
+        self.current_turn
+    }
+
+    pub fn turn_number(&self) -> usize {
+        self.turn_number
+    }
+
+    pub fn phase(&self) -> TurnPhase {
+        self.phase
+    }
+
+    /// Validates and performs an action on behalf of the given player, returning the events describing what changed.
+    ///
+    /// Nothing is changed if an error is returned.
+    pub fn apply(
+        &mut self,
+        token: OwnershipToken,
+        action: Action,
+    ) -> Result<Vec<GameEvent>, ActionError> {
+        self.events.clear();
+        self.check_on_board(action)?;
+        Self::check_resource_counts(action)?;
+
+        match action {
+            Action::RollDice => {
+                self.roll_dice(token)?;
+            }
+            Action::BuildSettlement(position) => {
+                self.play_building(Building::new(BuildingType::Settlement, token), position)?
+            }
+            Action::BuildCity(position) => {
+                self.play_building(Building::new(BuildingType::City, token), position)?
+            }
+            Action::BuildRoad(position) => {
+                self.play_transport(Transport::new(TransportType::Road, token), position)?
+            }
+            Action::BuildBoat(position) => {
+                self.play_transport(Transport::new(TransportType::Boat, token), position)?
+            }
+            Action::Discard(resources) => self.discard(token, resources)?,
+            Action::MoveRobber(position) => self.move_robber(token, position)?,
+            Action::Steal(victim) => self.steal(token, victim)?,
+            Action::BuyDevelopmentCard => self.buy_development_card(token)?,
+            Action::PlayDevelopmentCard(play) => self.play_development_card(token, play)?,
+            Action::ProposeTrade { to, give, receive } => {
+                self.propose_trade(token, to, give, receive)?;
+            }
+            Action::AcceptTrade(offer) => self.accept_trade(token, offer)?,
+            Action::RejectTrade(offer) => self.reject_trade(token, offer)?,
+            Action::CounterTrade {
+                offer,
+                give,
+                receive,
+            } => {
+                self.counter_trade(token, offer, give, receive)?;
+            }
+            Action::WithdrawTrade(offer) => self.withdraw_trade(token, offer)?,
+            Action::TradeWithBank { give, receive } => {
+                self.trade_with_bank(token, give, receive)?
+            }
+            Action::EndTurn => self.end_turn(token)?,
+        }
+
+        Ok(std::mem::take(&mut self.events))
+    }
+
+    /// Actions can be read from anywhere, such as JSON sent to the WASM interface, so their positions are checked
+    /// against the board before any table is looked at.
+    fn check_on_board(&self, action: Action) -> Result<(), ActionError> {
+        match action {
+            Action::BuildSettlement(position) | Action::BuildCity(position)
+                if !self.board.contains_corner(position) =>
+            {
+                Err(ActionError::CornerNotOnBoard(position))
+            }
+            Action::BuildRoad(position) | Action::BuildBoat(position)
+                if !self.board.contains_edge(position) =>
+            {
+                Err(ActionError::EdgeNotOnBoard(position))
+            }
+            Action::MoveRobber(position) if !self.board.contains_hex(position) => {
+                Err(ActionError::HexNotOnBoard(position))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Cards given in an action must add up to a number which can be counted, so no sum of them can overflow.
+    fn check_resource_counts(action: Action) -> Result<(), ActionError> {
+        let maps = match action {
+            Action::Discard(resources) => [Some(resources), None],
+            Action::ProposeTrade { give, receive, .. }
+            | Action::CounterTrade { give, receive, .. }
+            | Action::TradeWithBank { give, receive } => [Some(give), Some(receive)],
+            _ => [None, None],
+        };
+
+        match maps
+            .into_iter()
+            .flatten()
+            .find(|resources| resources.checked_total().is_none())
+        {
+            Some(resources) => Err(ActionError::TooManyResources(resources)),
+            None => Ok(()),
+        }
+    }
+
+    /// Rolls the dice for the current player and hands out resources, moving on to the trading phase. A 7 activates
+    /// the robber instead.
+    pub fn roll_dice(&mut self, token: OwnershipToken) -> Result<Dice, TurnError> {
+        self.check_turn(token)?;
+
+        if self.phase != TurnPhase::Roll {
+            return Err(TurnError::WrongPhase { phase: self.phase });
+        }
+
+        let dice = Dice::roll(&mut self.rng);
+
+        self.emit(GameEvent::DiceRolled {
+            player: token,
+            dice,
+        });
+
+        if dice.sum() == 7 {
+            self.activate_robber();
+        } else {
+            self.distribute_resources(dice.sum());
+            self.set_phase(TurnPhase::Trade);
+        }
+
+        Ok(dice)
+    }
+
+    pub fn end_turn(&mut self, token: OwnershipToken) -> Result<(), TurnError> {
+        self.check_turn(token)?;
+
+        if !self.phase.can_end_turn() {
+            return Err(TurnError::WrongPhase { phase: self.phase });
+        }
+
+        self.emit(GameEvent::TurnEnded { player: token });
+
+        self.played_development_card = false;
+        self.next_turn();
+        self.set_phase(TurnPhase::Roll);
+        self.check_victory();
+
+        Ok(())
+    }
+
+    /// Registers a subscriber which is told about every event from now on, in the order they happen.
+    pub fn subscribe(&mut self, subscriber: impl EventSubscriber + 'static) {
+        self.subscribers.push(Box::new(subscriber));
+    }
+
+    fn emit(&mut self, event: GameEvent) {
+        self.subscribers.notify(&event);
+        self.events.push(event);
+    }
+
+    fn set_phase(&mut self, phase: TurnPhase) {
+        if self.phase != phase {
+            if self.phase == TurnPhase::Trade {
+                self.close_trade_offers();
+            }
+            self.phase = phase;
+            self.emit(GameEvent::PhaseChanged { phase });
+        }
+    }
+
+    fn check_turn(&self, token: OwnershipToken) -> Result<(), TurnError> {
+        if token != self.current_turn {
+            return Err(TurnError::NotPlayersTurn {
+                current: self.current_turn,
+                attempted: token,
+            });
+        }
+
+        Ok(())
+    }
+
+    fn next_turn(&mut self) {
+        self.current_turn = self
+            .players
+            .iter()
+            .cycle()
+            .skip_while(|p| p.token() != self.current_turn)
+            .nth(1)
+            .unwrap()
+            .token();
+
+        self.turn_number += 1;
+    }
+
+    fn previous_turn(&mut self) {
         self.current_turn = self
             .players
             .iter()
+            .rev()
             .cycle()
             .skip_while(|p| p.token() != self.current_turn)
-            .next()
+            .nth(1)
             .unwrap()
             .token();
+
+        self.turn_number += 1;
+    }
+
+    fn is_first_player(&self) -> bool {
+        self.players
+            .first()
+            .is_some_and(|p| p.token() == self.current_turn)
+    }
+
+    fn is_last_player(&self) -> bool {
+        self.players
+            .last()
+            .is_some_and(|p| p.token() == self.current_turn)
+    }
+
+    /// Moves the setup along once the current player has placed their transport, following the snake order.
+    fn advance_setup(&mut self, round: SetupRound) {
+        let phase = match round {
+            SetupRound::First if self.is_last_player() => TurnPhase::Setup {
+                round: SetupRound::Second,
+                step: SetupStep::Building,
+            },
+            SetupRound::First => {
+                self.next_turn();
+                TurnPhase::START
+            }
+            SetupRound::Second if self.is_first_player() => TurnPhase::Roll,
+            SetupRound::Second => {
+                self.previous_turn();
+                TurnPhase::Setup {
+                    round: SetupRound::Second,
+                    step: SetupStep::Building,
+                }
+            }
+        };
+
+        self.set_phase(phase);
+    }
+
+    /// Checks that it is the owner's turn, and that they are allowed to build during the current phase.
+    fn check_build_phase(&self, owner: OwnershipToken) -> Result<(), TurnError> {
+        self.check_turn(owner)?;
+
+        if !self.phase.can_build() {
+            return Err(TurnError::WrongPhase { phase: self.phase });
+        }
+
+        Ok(())
     }
 
     pub fn find_building(&self, position: CornerPosition) -> Option<Building> {
         self.board.get_building(position)
     }
 
+    /// Every building on the board, along with its position.
+    pub fn buildings(&self) -> impl Iterator<Item = (Building, CornerPosition)> {
+        self.buildings.iter().map(|(b, p, _)| (*b, *p))
+    }
+
     pub fn can_play_building(
         &self,
         building: Building,
         position: CornerPosition,
     ) -> Result<(), BuildError> {
+        self.check_build_phase(building.owner())?;
+
+        if let TurnPhase::Setup { step, .. } = self.phase
+            && (step != SetupStep::Building || building.r#type() != BuildingType::Settlement)
+        {
+            return Err(TurnError::WrongPhase { phase: self.phase }.into());
+        }
+
+        if let TurnPhase::RoadBuilding { .. } = self.phase {
+            return Err(TurnError::WrongPhase { phase: self.phase }.into());
+        }
+
         for corner in self.board.neighboring_corners(position) {
             if self.find_building(corner).is_some() {
                 return Err(BuildError::BuildingIsTooCloseToExisting);
@@ -174,10 +550,10 @@ impl Game {
             return Err(BuildError::BuildingCutsOffRoad);
         }
 
-        // Exception for first turn, where roads have not been placed yet.
+        // Exception for setup, where roads have not been placed yet.
         if same_ownership == 0
             && building.r#type() == BuildingType::Settlement
-            && self.turn_number > 0
+            && !self.phase.is_setup()
         {
             return Err(BuildError::BuildingHasNoRoad);
         }
@@ -205,20 +581,51 @@ impl Game {
     ) -> Result<(), BuildError> {
         self.can_play_building(building, position)?;
 
-        let turn_number = self.turn_number;
-
-        let player = self.find_player_mut(building.owner());
+        let is_free = self.phase.is_free_placement();
+        let owner = building.owner();
+        let structure: StructureType = building.into();
 
-        player.play_structure(building.into(), turn_number == 0)?;
+        // Everything that can fail is checked before anything is changed.
+        self.find_player(owner)
+            .try_play_structure(structure, is_free)?;
 
         self.board
             .set_building(building, position)
-            .expect("Invalid position!");
+            .map_err(|_| BuildError::OutOfBounds)?;
 
-        self.buildings.push((
-            building,
-            self.board.neighboring_hex_for_corner(position).collect(),
-        ));
+        self.find_player_mut(owner)
+            .play_structure(structure, is_free)?;
+
+        if !is_free {
+            self.return_to_bank(owner, structure.cost());
+        }
+
+        let hexes = self.board.neighboring_hex_for_corner(position).collect();
+        match self.buildings.iter_mut().find(|(_, p, _)| *p == position) {
+            Some(entry) => *entry = (building, position, hexes),
+            None => self.buildings.push((building, position, hexes)),
+        }
+
+        self.emit(GameEvent::BuildingPlaced { building, position });
+
+        if let TurnPhase::Setup { round, .. } = self.phase
+            && self.starting_resources.is_granted_in(round)
+        {
+            self.grant_starting_resources(owner, position);
+        }
+
+        self.update_longest_road();
+
+        let phase = match self.phase {
+            TurnPhase::Setup { round, .. } => TurnPhase::Setup {
+                round,
+                step: SetupStep::Transport(position),
+            },
+            _ => TurnPhase::Build,
+        };
+
+        self.set_phase(phase);
+        self.check_victory();
 
         Ok(())
     }
@@ -231,6 +638,16 @@ impl Game {
         &self,
         transport: Transport,
         position: EdgePosition,
+    ) -> Result<(), BuildError> {
+        self.check_build_phase(transport.owner())?;
+        self.check_transport_position(transport, position)
+    }
+
+    /// Checks where a transport may go, regardless of whose turn or which phase it is.
+    fn check_transport_position(
+        &self,
+        transport: Transport,
+        position: EdgePosition,
     ) -> Result<(), BuildError> {
         if self.find_transport(position).is_some() {
             return Err(BuildError::StructureAlreadyExists);
@@ -255,43 +672,49 @@ impl Game {
             }
         }
 
-        let neighbor_count = self
-            .neighboring_transport(transport.owner(), position)
-            .count();
-
-        // Exception for first turn, where there are no neighbors.
-        if neighbor_count == 0 && self.turn_number > 0 {
-            return Err(BuildError::TransportMustBeContiguous);
+        if let TurnPhase::Setup { step, .. } = self.phase {
+            return match step {
+                SetupStep::Transport(corner)
+                    if self
+                        .board
+                        .neighboring_corners_for_edge(position)
+                        .any(|c| c == corner) =>
+                {
+                    Ok(())
+                }
+                SetupStep::Transport(_) => Err(BuildError::SetupTransportMustTouchBuilding),
+                SetupStep::Building => Err(TurnError::WrongPhase { phase: self.phase }.into()),
+            };
         }
 
-        let mut unowned_buildings = self
-            .board
-            .neighboring_corners_for_edge(position)
-            .filter(|c| {
-                self.find_building(*c)
-                    .is_some_and(|b| b.owner() != transport.owner())
-            });
+        let mut is_interrupted = false;
 
-        let mut valid_edges = 0;
-        for edge in self.neighboring_transport(transport.owner(), position) {
-            for corner in self.board.neighboring_corners_for_edge(edge) {
-                match unowned_buildings.find(|c| *c == corner) {
-                    Some(_) => valid_edges += 1,
-                    None => (),
+        for corner in self.board.neighboring_corners_for_edge(position) {
+            match self.find_building(corner) {
+                Some(b) if b.owner() == transport.owner() => return Ok(()),
+                Some(_) => {
+                    is_interrupted |= self
+                        .neighboring_transport_for_corner(transport.owner(), corner)
+                        .next()
+                        .is_some();
+                }
+                None => {
+                    if self
+                        .neighboring_transport_for_corner(transport.owner(), corner)
+                        .next()
+                        .is_some()
+                    {
+                        return Ok(());
+                    }
                 }
-            }
-
-            if valid_edges > 0 {
-                break;
             }
         }
 
-        // Exception for first turn, where there are no neighbors.
-        if valid_edges == 0 && self.turn_number > 0 {
-            return Err(BuildError::TransportInterruptsBuilding);
+        if is_interrupted {
+            Err(BuildError::TransportInterruptsBuilding)
+        } else {
+            Err(BuildError::TransportMustBeContiguous)
         }
-
-        Ok(())
     }
 
     pub fn play_transport(
@@ -301,46 +724,94 @@ impl Game {
     ) -> Result<(), BuildError> {
         self.can_play_transport(transport, position)?;
 
-        let turn_number = self.turn_number;
+        let is_free = self.phase.is_free_placement();
+        let owner = transport.owner();
+        let structure: StructureType = transport.into();
 
-        let player = self.find_player_mut(transport.owner());
-
-        player.play_structure(transport.into(), turn_number == 0)?;
+        self.find_player(owner)
+            .try_play_structure(structure, is_free)?;
 
         self.board
             .set_transport(transport, position)
-            .expect("Invalid position!");
+            .map_err(|_| BuildError::OutOfBounds)?;
+
+        self.find_player_mut(owner)
+            .play_structure(structure, is_free)?;
+
+        if !is_free {
+            self.return_to_bank(owner, structure.cost());
+        }
 
-        self.update_last_played_transport(transport.owner(), position);
+        self.transports.push((transport, position));
+
+        self.emit(GameEvent::TransportPlaced {
+            transport,
+            position,
+        });
+
+        self.update_longest_road();
+
+        match self.phase {
+            TurnPhase::Setup { round, .. } => self.advance_setup(round),
+            TurnPhase::RoadBuilding { remaining }
+                if remaining > 1 && self.free_transports(owner, 1) > 0 =>
+            {
+                self.set_phase(TurnPhase::RoadBuilding {
+                    remaining: remaining - 1,
+                })
+            }
+            TurnPhase::RoadBuilding { .. } => self.set_phase(self.resume_phase),
+            _ => self.set_phase(TurnPhase::Build),
+        }
+
+        self.check_victory();
 
         Ok(())
     }
 
-    fn neighboring_transport(
+    fn neighboring_transport_for_corner(
         &self,
         owner: OwnershipToken,
-        position: EdgePosition,
-    ) -> impl Iterator<Item = EdgePosition> + Clone + Debug {
+        position: CornerPosition,
+    ) -> impl Iterator<Item = EdgePosition> + Clone {
         self.board
-            .neighboring_edges(position)
+            .neighboring_edges_for_corner(position)
             .filter(move |p| self.find_transport(*p).is_some_and(|t| t.owner() == owner))
     }
 
-    pub fn distribute_resources(&mut self, roll: u8) {
-        for player in self.players.iter_mut() {
-            let resources: ResourceMap = self
-                .buildings
-                .iter()
-                .filter(|(b, _)| b.owner() == player.token())
-                .map(|(b, pos)| {
-                    pos.iter()
-                        .filter_map(|p| Self::determine_resource(&self.board, *b, *p, roll))
-                })
-                .flatten()
-                .collect();
+    fn distribute_resources(&mut self, roll: u8) {
+        let production = self
+            .players
+            .iter()
+            .map(|player| {
+                let resources: ResourceMap = self
+                    .buildings
+                    .iter()
+                    .filter(|(b, _, _)| b.owner() == player.token())
+                    .map(|(b, _, pos)| {
+                        pos.iter()
+                            .filter_map(|p| Self::determine_resource(&self.board, *b, *p, roll))
+                    })
+                    .flatten()
+                    .collect();
+
+                (player.token(), resources)
+            })
+            .collect();
 
-            player.add_resources(resources);
-        }
+        self.produce_resources(production);
+    }
+
+    /// Hands out one card for each resource hex next to a settlement placed during setup.
+    fn grant_starting_resources(&mut self, owner: OwnershipToken, position: CornerPosition) {
+        let resources = self
+            .board
+            .neighboring_hex_for_corner(position)
+            .filter_map(|p| self.board.get_tile(p).get_tile_type().get_resource_type())
+            .map(|r| (r, 1))
+            .collect();
+
+        self.produce_resources(vec![(owner, resources)]);
     }
 
     fn determine_resource(
@@ -365,189 +836,72 @@ impl Game {
         }
     }
 
-    /// Big boi!
+    /// Finds the longest trail through the owner's transports, using each transport at most once. Trails cannot pass
+    /// through another player's building, and can only switch between roads and boats at one of the owner's buildings.
     ///
-    /// Iterates through all placed transports, keeping track when new branches appear, and then when all branches
-    /// are dead-ended, find the combination that is the biggest.
+    /// This is an exhaustive search starting from every transport in both directions, which is fine for the handful of
+    /// transports a player can own.
     pub fn calculate_longest_road(&self, owner: OwnershipToken) -> u32 {
-        let last_road = match self.get_last_played_transport(owner) {
-            Some(p) => p,
-            None => return 0,
-        };
-
-        let segment = TransportSegment::new(owner, last_road);
+        let network: Vec<(Transport, EdgePosition)> = self
+            .transports
+            .iter()
+            .filter(|(t, _)| t.owner() == owner)
+            .copied()
+            .collect();
 
-        let mut segments: Vec<TransportSegment> = iter::once(segment).collect();
+        let mut visited = vec![false; network.len()];
+        let mut longest = 0;
 
-        while !Self::all_segments_finished(&segments) {
-            segments = self.advance_segments(segments);
+        for (index, (_, position)) in network.iter().enumerate() {
+            for corner in self.board.neighboring_corners_for_edge(*position) {
+                visited[index] = true;
+                longest = longest.max(1 + self.extend_trail(&network, &mut visited, index, corner));
+                visited[index] = false;
+            }
         }
 
-        if let Some(longest) = Self::find_longest_segment(segments) {
-            longest.length()
-        } else {
-            0
-        }
+        longest
     }
 
-    fn advance_segments(&self, segments: Vec<TransportSegment>) -> Vec<TransportSegment> {
-        let mut new_segments: Vec<TransportSegment> = Vec::with_capacity(segments.len());
-
-        for segment in segments {
-            if segment.is_finished() {
-                new_segments.push(segment);
-                continue;
-            }
-
-            let current_position = segment.current_position();
-
-            let mut is_building_blocking = false;
-
-            for corner in self.board.neighboring_corners_for_edge(current_position) {
-                if segment.is_corner_behind_current(corner) {
-                    continue;
-                }
+    /// Returns the length of the longest trail continuing from the transport at `from` through `corner`.
+    fn extend_trail(
+        &self,
+        network: &[(Transport, EdgePosition)],
+        visited: &mut [bool],
+        from: usize,
+        corner: CornerPosition,
+    ) -> u32 {
+        let (transport, _) = network[from];
+
+        let is_own_building = match self.find_building(corner) {
+            Some(b) if b.owner() != transport.owner() => return 0,
+            Some(_) => true,
+            None => false,
+        };
 
-                is_building_blocking = self
-                    .find_building(corner)
-                    .is_some_and(|b| b.owner() != segment.owner());
-            }
+        let mut longest = 0;
 
-            if is_building_blocking {
-                let mut new_segment = segment.clone();
-                new_segment.finished();
-                new_segments.push(new_segment);
+        for (index, (next, position)) in network.iter().enumerate() {
+            if visited[index] || (next.r#type() != transport.r#type() && !is_own_building) {
                 continue;
             }
 
-            let neighboring_transport =
-                self.neighboring_transport(segment.owner(), segment.current_position());
+            let mut corners = self.board.neighboring_corners_for_edge(*position);
 
-            let next_positions: Vec<EdgePosition> =
-                segment.next_positions(neighboring_transport).collect();
-
-            if next_positions.len() == 0 {
-                let mut new_segment = segment.clone();
-                new_segment.finished();
-                new_segments.push(new_segment);
+            if !corners.clone().any(|c| c == corner) {
                 continue;
             }
 
-            for position in next_positions {
-                let mut new_segment = segment.clone();
-                new_segment.update(position);
-                new_segments.push(new_segment);
-            }
-        }
-
-        new_segments
-    }
-
-    fn update_last_played_transport(&mut self, owner: OwnershipToken, position: EdgePosition) {
-        if let Some((_, p)) = self.transports.iter_mut().find(|(o, _)| *o == owner) {
-            *p = Some(position);
-        }
-    }
-
-    fn get_last_played_transport(&self, owner: OwnershipToken) -> Option<EdgePosition> {
-        if let Some((_, p)) = self.transports.iter().find(|(o, _)| *o == owner).copied() {
-            p
-        } else {
-            None
-        }
-    }
-
-    fn find_longest_segment(segments: Vec<TransportSegment>) -> Option<TransportSegment> {
-        let mut candidates: Vec<(TransportSegment, TransportSegment)> =
-            Vec::with_capacity(segments.len());
-
-        let mut segments = segments.into_iter();
-        let mut longest_segment = segments.clone().next()?;
-
-        let mut shortest_overlap: usize = MAX;
-
-        while let Some(segment) = segments.next() {
-            if segment.length() > longest_segment.length() {
-                longest_segment = segment.clone();
-            }
-
-            for other_segment in segments.clone() {
-                let overlap = segment.history_overlap(&other_segment).count();
-                if overlap < shortest_overlap {
-                    shortest_overlap = overlap;
-                    candidates.clear();
-                    candidates.push((segment.clone(), other_segment));
-                } else if overlap == shortest_overlap {
-                    candidates.push((segment.clone(), other_segment));
-                }
-            }
-        }
-
-        for (first, second) in candidates.into_iter() {
-            let combined = match Self::combine_segments(first, second) {
-                Some(segment) => segment,
-                None => continue,
+            visited[index] = true;
+            let length = match corners.find(|c| *c != corner) {
+                Some(far) => 1 + self.extend_trail(network, visited, index, far),
+                None => 1,
             };
+            visited[index] = false;
 
-            if combined.length() > longest_segment.length() {
-                longest_segment = combined;
-            }
-        }
-
-        Some(longest_segment)
-    }
-
-    fn combine_segments(
-        first: TransportSegment,
-        second: TransportSegment,
-    ) -> Option<TransportSegment> {
-        let overlap = first.history_overlap(&second);
-
-        let mut first_history = first.history();
-        let mut second_history = second.history();
-
-        for position in overlap {
-            if let Some(index) = first_history.iter().position(|p| *p == position) {
-                first_history.remove(index);
-            }
-
-            if let Some(index) = second_history.iter().position(|p| *p == position) {
-                second_history.remove(index);
-            }
-        }
-
-        let pos1 = *first_history.get(0)?;
-        let pos2 = *second_history.get(0)?;
-
-        first_history.reverse();
-
-        if let Ok(gap) = pos1.find_gap(pos2) {
-            first_history.push(gap);
-        }
-
-        let combined_segment: Vec<EdgePosition> = first_history
-            .into_iter()
-            .chain(second_history.into_iter())
-            .collect();
-
-        let segment = TransportSegment::from_history(first.owner(), combined_segment);
-
-        if segment.is_continuous()? {
-            Some(segment)
-        } else {
-            None
-        }
-    }
-
-    fn all_segments_finished(segments: &[TransportSegment]) -> bool {
-        for segment in segments {
-            if segment.is_finished() {
-                continue;
-            } else {
-                return false;
-            }
+            longest = longest.max(length);
         }
 
-        return true;
+        longest
     }
 }
@@ -0,0 +1,88 @@
+use hexgrid::{corner::position::CornerPosition, hex::position::HexPosition};
+use serde::{Deserialize, Serialize};
+
+use crate::game::player::OwnershipToken;
+
+/// The stage of the game (or of the current player's turn), which determines what actions may be taken.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TurnPhase {
+    /// Initial placement. Every player places a settlement followed by a transport touching it, first in turn order
+    /// and then in reverse turn order.
+    Setup { round: SetupRound, step: SetupStep },
+    /// The current player must roll the dice before doing anything else.
+    Roll,
+    /// The dice have been rolled, and the current player may trade.
+    Trade,
+    /// The current player has started building, and may no longer trade.
+    Build,
+    /// A 7 was rolled or a robber card was played, and the robber must be dealt with before the turn can continue.
+    Robber(RobberStep),
+    /// The current player is placing the free transports granted by a road building card.
+    RoadBuilding { remaining: u8 },
+    /// A player has reached the victory point target, and no further actions may be taken.
+    GameOver { winner: OwnershipToken },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SetupRound {
+    First,
+    Second,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SetupStep {
+    Building,
+    /// Holds the position of the building that was just placed, which the transport must touch.
+    Transport(CornerPosition),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RobberStep {
+    /// Every player holding more than 7 cards must discard half of them, rounded down.
+    Discard,
+    /// The current player must move the robber to a different land hex.
+    Move,
+    /// The current player must steal from a player with a building next to the robber's new hex.
+    Steal(HexPosition),
+}
+
+impl TurnPhase {
+    pub const START: TurnPhase = TurnPhase::Setup {
+        round: SetupRound::First,
+        step: SetupStep::Building,
+    };
+
+    pub fn is_setup(&self) -> bool {
+        matches!(self, TurnPhase::Setup { .. })
+    }
+
+    /// Returns true if the current player is allowed to build, either during setup or after the dice have been rolled.
+    pub fn can_build(&self) -> bool {
+        matches!(
+            self,
+            TurnPhase::Setup { .. }
+                | TurnPhase::Trade
+                | TurnPhase::Build
+                | TurnPhase::RoadBuilding { .. }
+        )
+    }
+
+    /// Returns true if structures placed during this phase cost nothing.
+    pub fn is_free_placement(&self) -> bool {
+        matches!(
+            self,
+            TurnPhase::Setup { .. } | TurnPhase::RoadBuilding { .. }
+        )
+    }
+
+    /// Returns true if the current player may play a development card, which is allowed at any time during their own
+    /// turn outside of setup, as long as nothing else is pending.
+    pub fn can_play_development_card(&self) -> bool {
+        matches!(self, TurnPhase::Roll | TurnPhase::Trade | TurnPhase::Build)
+    }
+
+    /// Returns true if the current player is allowed to end their turn.
+    pub fn can_end_turn(&self) -> bool {
+        matches!(self, TurnPhase::Trade | TurnPhase::Build)
+    }
+}
@@ -1,9 +1,14 @@
 use std::sync::atomic::{AtomicU64, Ordering};
 
+use serde::{Deserialize, Serialize};
+
 use crate::{
-    game::{error::BuildError, hand::Hand},
+    game::{
+        error::{BuildError, DevelopmentCardError},
+        hand::Hand,
+    },
     object::{
-        card::{ResourceCard, ResourceMap},
+        card::{DevelopmentCard, DevelopmentCardType, ResourceCard, ResourceMap},
         resource::{ResourceType, Resources},
         structure::{OwnedStructures, StructureType},
     },
@@ -11,16 +16,23 @@ use crate::{
 
 static NEXT: AtomicU64 = AtomicU64::new(0);
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct OwnershipToken(u64);
 
 impl OwnershipToken {
     pub fn new() -> Self {
         Self(NEXT.fetch_add(1, Ordering::Relaxed))
     }
+
+    /// Pushes the counter past a restored token, so that tokens created afterwards in a fresh process never collide
+    /// with it. Only done for tokens of a restored `Game`, as tokens in untrusted input like actions could otherwise
+    /// exhaust the counter.
+    pub(super) fn reserve(self) {
+        NEXT.fetch_max(self.0.saturating_add(1), Ordering::Relaxed);
+    }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Player {
     hand: Hand,
     owned_structures: OwnedStructures,
@@ -36,11 +48,29 @@ impl Player {
         }
     }
 
+    /// Recreates a known player with an empty hand, keeping their token.
+    pub(super) fn restore(token: OwnershipToken, owned_structures: OwnedStructures) -> Self {
+        Self {
+            hand: Hand::new(),
+            owned_structures,
+            token,
+        }
+    }
+
     pub fn token(&self) -> OwnershipToken {
         self.token
     }
 
-    pub fn try_play_structure(&self, structure: StructureType) -> Result<(), BuildError> {
+    pub fn owned_structures(&self) -> OwnedStructures {
+        self.owned_structures
+    }
+
+    /// Checks that the player has the structure left to place and, unless it is free, can pay for it.
+    pub fn try_play_structure(
+        &self,
+        structure: StructureType,
+        is_free: bool,
+    ) -> Result<(), BuildError> {
         if self.owned_structures.get_structure(structure) == 0 {
             return Err(BuildError::NoStructures {
                 token: self.token,
@@ -48,6 +78,10 @@ impl Player {
             });
         }
 
+        if is_free {
+            return Ok(());
+        }
+
         let mut insufficient_resources = Vec::<ResourceCard>::new();
 
         for resource in Resources::new() {
@@ -66,14 +100,14 @@ impl Player {
         Ok(())
     }
 
+    /// Takes the structure out of the player's supply, returning the settlement to it when upgrading to a city. Paying
+    /// for the structure is left to the caller, since the cards go back to the bank.
     pub fn play_structure(
         &mut self,
         structure: StructureType,
-        is_first_turn: bool,
+        is_free: bool,
     ) -> Result<(), BuildError> {
-        if is_first_turn {
-            self.try_play_structure(structure)?;
-        }
+        self.try_play_structure(structure, is_free)?;
 
         if structure == StructureType::City {
             self.owned_structures
@@ -99,4 +133,40 @@ impl Player {
     pub fn sub_resource(&mut self, resource: ResourceType, count: u32) {
         self.hand.sub_resource_card(resource, count);
     }
+
+    pub fn sub_resources(&mut self, resources: ResourceMap) {
+        *self.hand.get_mut_resources() = self.hand.get_resources() - resources
+    }
+
+    pub fn get_resources(&self) -> ResourceMap {
+        self.hand.get_resources()
+    }
+
+    pub fn add_development_card(&mut self, card: DevelopmentCard) {
+        self.hand.add_development_card(card);
+    }
+
+    pub fn can_play_development_card(
+        &self,
+        r#type: DevelopmentCardType,
+        turn_number: usize,
+    ) -> Result<(), DevelopmentCardError> {
+        self.hand.can_play_development_card(r#type, turn_number)
+    }
+
+    pub fn play_development_card(
+        &mut self,
+        r#type: DevelopmentCardType,
+        turn_number: usize,
+    ) -> Result<(), DevelopmentCardError> {
+        self.hand.play_development_card(r#type, turn_number)
+    }
+
+    pub fn development_cards(&self) -> impl Iterator<Item = &DevelopmentCard> {
+        self.hand.development_cards()
+    }
+
+    pub fn count_structure(&self, structure: StructureType) -> u32 {
+        self.owned_structures.get_structure(structure)
+    }
 }
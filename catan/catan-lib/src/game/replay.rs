@@ -0,0 +1,177 @@
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::game::{
+    Game, action::Action, error::ActionError, event::GameEvent, player::OwnershipToken,
+    save::SaveError,
+};
+
+#[derive(Error, Debug)]
+pub enum ReplayError {
+    #[error(transparent)]
+    Save(#[from] SaveError),
+    #[error("action {index} could not be replayed: {error}")]
+    Action { index: usize, error: ActionError },
+    #[error(
+        "replay diverged after action {index}: expected state hash {expected:#018x}, found {found:#018x}"
+    )]
+    Divergence {
+        index: usize,
+        expected: u64,
+        found: u64,
+    },
+    #[error("replay diverged at the start of turn {turn_number}: the RNG state does not match")]
+    RngDivergence { turn_number: usize },
+    #[error("turn {0} was never reached in this replay")]
+    TurnNotReached(usize),
+    #[error("the log holds {len} actions, but {end} are needed to replay it")]
+    MissingEntries { end: usize, len: usize },
+}
+
+/// An action that was successfully applied, together with the hash of the game state right after it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReplayEntry {
+    pub player: OwnershipToken,
+    pub action: Action,
+    pub state_hash: u64,
+}
+
+/// The RNG state at the start of a turn. `action_index` is the number of entries applied before the turn started.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Checkpoint {
+    pub turn_number: usize,
+    pub action_index: usize,
+    pub rng_state: Vec<u8>,
+}
+
+/// An append-only log of every action applied to a game, starting from a snapshot of its initial state (which
+/// includes the seeded RNG). Replaying the log reconstructs any prior state exactly.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReplayLog {
+    initial: Vec<u8>,
+    entries: Vec<ReplayEntry>,
+    checkpoints: Vec<Checkpoint>,
+}
+
+impl ReplayLog {
+    /// Starts a new log from the current state of `game`.
+    pub fn new(game: &Game) -> Result<Self, SaveError> {
+        Ok(Self {
+            initial: game.to_bytes()?,
+            entries: Vec::new(),
+            checkpoints: vec![game.checkpoint(0)],
+        })
+    }
+
+    pub fn entries(&self) -> &[ReplayEntry] {
+        &self.entries
+    }
+
+    pub fn checkpoints(&self) -> &[Checkpoint] {
+        &self.checkpoints
+    }
+
+    /// Applies `action` to `game` and records it if it succeeds. Rejected actions leave the game untouched and are not
+    /// logged.
+    pub fn apply(
+        &mut self,
+        game: &mut Game,
+        player: OwnershipToken,
+        action: Action,
+    ) -> Result<Vec<GameEvent>, ActionError> {
+        let turn_number = game.turn_number();
+        let events = game.apply(player, action)?;
+
+        self.entries.push(ReplayEntry {
+            player,
+            action,
+            state_hash: game.state_hash(),
+        });
+        if game.turn_number() != turn_number {
+            self.checkpoints.push(game.checkpoint(self.entries.len()));
+        }
+
+        Ok(events)
+    }
+
+    /// Replays the whole log, verifying every intermediate state against the recorded hashes.
+    pub fn replay(&self) -> Result<Game, ReplayError> {
+        self.replay_until(self.entries.len())
+    }
+
+    /// Replays the log up to the start of turn `turn_number`, before its first action.
+    pub fn replay_to_turn(&self, turn_number: usize) -> Result<Game, ReplayError> {
+        let checkpoint = self
+            .checkpoints
+            .iter()
+            .find(|c| c.turn_number == turn_number)
+            .ok_or(ReplayError::TurnNotReached(turn_number))?;
+
+        let game = self.replay_until(checkpoint.action_index)?;
+        if game.rng.serialize_state().as_slice() != checkpoint.rng_state {
+            return Err(ReplayError::RngDivergence { turn_number });
+        }
+
+        Ok(game)
+    }
+
+    fn replay_until(&self, end: usize) -> Result<Game, ReplayError> {
+        let mut game = Game::from_bytes(&self.initial)?;
+        let mut checkpoints = self.checkpoints.iter().peekable();
+
+        // Logs come from bug reports, so a checkpoint can point past the end of a truncated or edited one.
+        let entries = self.entries.get(..end).ok_or(ReplayError::MissingEntries {
+            end,
+            len: self.entries.len(),
+        })?;
+
+        for (index, entry) in entries.iter().enumerate() {
+            while let Some(checkpoint) = checkpoints.next_if(|c| c.action_index == index) {
+                if game.rng.serialize_state().as_slice() != checkpoint.rng_state {
+                    return Err(ReplayError::RngDivergence {
+                        turn_number: checkpoint.turn_number,
+                    });
+                }
+            }
+
+            game.apply(entry.player, entry.action)
+                .map_err(|error| ReplayError::Action { index, error })?;
+
+            let found = game.state_hash();
+            if found != entry.state_hash {
+                return Err(ReplayError::Divergence {
+                    index,
+                    expected: entry.state_hash,
+                    found,
+                });
+            }
+        }
+
+        Ok(game)
+    }
+}
+
+impl Game {
+    /// A hash of the complete game state, stable across processes and platforms.
+    pub fn state_hash(&self) -> u64 {
+        let bytes = postcard::to_allocvec(self).expect("Game is always serializable!");
+        fnv1a(&bytes)
+    }
+
+    fn checkpoint(&self, action_index: usize) -> Checkpoint {
+        Checkpoint {
+            turn_number: self.turn_number,
+            action_index,
+            rng_state: self.rng.serialize_state().to_vec(),
+        }
+    }
+}
+
+fn fnv1a(bytes: &[u8]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+    const PRIME: u64 = 0x100000001b3;
+
+    bytes.iter().fold(OFFSET_BASIS, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(PRIME)
+    })
+}
@@ -0,0 +1,199 @@
+use hexgrid::hex::position::HexPosition;
+use rand::prelude::*;
+
+use crate::{
+    game::{
+        Game,
+        error::{RobberError, TurnError},
+        event::GameEvent,
+        phase::{RobberStep, TurnPhase},
+        player::OwnershipToken,
+    },
+    object::{TileType, card::ResourceMap, resource::Resources},
+};
+
+/// Players holding more cards than this must discard half of their hand when a 7 is rolled.
+const DISCARD_LIMIT: u32 = 7;
+
+impl Game {
+    /// Called when a 7 is rolled. Players with too many cards must discard first, otherwise the robber is moved
+    /// immediately.
+    pub(super) fn activate_robber(&mut self) {
+        self.resume_phase = TurnPhase::Trade;
+        self.discards = self
+            .players
+            .iter()
+            .map(|p| (p.token(), p.get_resources().total()))
+            .filter(|(_, total)| *total > DISCARD_LIMIT)
+            .map(|(token, total)| (token, total / 2))
+            .collect();
+
+        if self.discards.is_empty() {
+            self.set_phase(TurnPhase::Robber(RobberStep::Move));
+        } else {
+            self.set_phase(TurnPhase::Robber(RobberStep::Discard));
+        }
+    }
+
+    /// Returns the number of cards the given player still has to discard.
+    pub fn pending_discard(&self, token: OwnershipToken) -> Option<u32> {
+        self.discards
+            .iter()
+            .find(|(t, _)| *t == token)
+            .map(|(_, count)| *count)
+    }
+
+    pub fn discard(
+        &mut self,
+        token: OwnershipToken,
+        resources: ResourceMap,
+    ) -> Result<(), RobberError> {
+        if self.phase != TurnPhase::Robber(RobberStep::Discard) {
+            return Err(TurnError::WrongPhase { phase: self.phase }.into());
+        }
+
+        let Some(expected) = self.pending_discard(token) else {
+            return Err(RobberError::NoDiscardRequired { token });
+        };
+
+        if resources.total() != expected {
+            return Err(RobberError::WrongDiscardCount {
+                expected,
+                found: resources.total(),
+            });
+        }
+
+        let player = self.find_player_mut(token);
+        let held = player.get_resources();
+
+        if !held.contains(&resources) {
+            return Err(RobberError::InsufficientCards {
+                discard: resources,
+                held,
+            });
+        }
+
+        self.return_to_bank(token, resources);
+        self.discards.retain(|(t, _)| *t != token);
+
+        self.emit(GameEvent::CardsDiscarded {
+            player: token,
+            resources,
+        });
+
+        if self.discards.is_empty() {
+            self.set_phase(TurnPhase::Robber(RobberStep::Move));
+        }
+
+        Ok(())
+    }
+
+    pub fn can_move_robber(
+        &self,
+        token: OwnershipToken,
+        position: HexPosition,
+    ) -> Result<(), RobberError> {
+        self.check_turn(token)?;
+
+        if self.phase != TurnPhase::Robber(RobberStep::Move) {
+            return Err(TurnError::WrongPhase { phase: self.phase }.into());
+        }
+
+        if self.board.has_robber(position) {
+            return Err(RobberError::SameHex);
+        }
+
+        if self.board.get_tile(position).get_tile_type() == TileType::Water {
+            return Err(RobberError::NotLand { position });
+        }
+
+        Ok(())
+    }
+
+    /// Moves the robber, then either waits for the current player to pick a victim or, if nobody can be stolen from,
+    /// resumes the turn where it left off.
+    pub fn move_robber(
+        &mut self,
+        token: OwnershipToken,
+        position: HexPosition,
+    ) -> Result<(), RobberError> {
+        self.can_move_robber(token, position)?;
+
+        self.board.move_robber(position);
+
+        self.emit(GameEvent::RobberMoved {
+            player: token,
+            position,
+        });
+
+        if self.robber_victims(token, position).next().is_some() {
+            self.set_phase(TurnPhase::Robber(RobberStep::Steal(position)));
+        } else {
+            self.set_phase(self.resume_phase);
+        }
+
+        Ok(())
+    }
+
+    /// Lists every player other than the thief who has a building next to the hex and at least one card.
+    pub fn robber_victims(
+        &self,
+        thief: OwnershipToken,
+        position: HexPosition,
+    ) -> impl Iterator<Item = OwnershipToken> {
+        self.players
+            .iter()
+            .filter(move |p| p.token() != thief && p.get_resources().total() > 0)
+            .filter(move |p| {
+                self.buildings
+                    .iter()
+                    .any(|(b, _, hexes)| b.owner() == p.token() && hexes.contains(&position))
+            })
+            .map(|p| p.token())
+    }
+
+    /// Takes a random card from the victim and gives it to the current player.
+    pub fn steal(
+        &mut self,
+        token: OwnershipToken,
+        victim: OwnershipToken,
+    ) -> Result<(), RobberError> {
+        self.check_turn(token)?;
+
+        let TurnPhase::Robber(RobberStep::Steal(position)) = self.phase else {
+            return Err(TurnError::WrongPhase { phase: self.phase }.into());
+        };
+
+        if !self.robber_victims(token, position).any(|v| v == victim) {
+            return Err(RobberError::InvalidVictim { victim });
+        }
+
+        let held = self.find_player(victim).get_resources();
+        let mut index = self.rng.random_range(0..held.total());
+        let resource = Resources::new()
+            .into_iter()
+            .find(|r| {
+                let count = held.get(*r).get_count();
+                if index < count {
+                    true
+                } else {
+                    index -= count;
+                    false
+                }
+            })
+            .expect("The victim has at least one card!");
+
+        self.find_player_mut(victim).sub_resource(resource, 1);
+        self.find_player_mut(token).add_resource(resource, 1);
+
+        self.emit(GameEvent::CardStolen {
+            thief: token,
+            victim,
+            resource,
+        });
+
+        self.set_phase(self.resume_phase);
+
+        Ok(())
+    }
+}
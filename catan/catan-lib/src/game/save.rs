@@ -0,0 +1,107 @@
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::game::Game;
+
+/// The version of the save format written by this build. Bump it whenever the serialized shape of `Game` changes.
+/// Saves are only loaded by the exact version that wrote them; older and newer ones are rejected rather than
+/// migrated, since postcard saves cannot be read at all once a field changes.
+pub const SAVE_VERSION: u32 = 12;
+
+#[derive(Error, Debug)]
+pub enum SaveError {
+    #[error("save was written by an unsupported format version {0} (expected {SAVE_VERSION})")]
+    UnsupportedVersion(u32),
+    #[error("malformed JSON save: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("malformed binary save: {0}")]
+    Binary(#[from] postcard::Error),
+}
+
+#[derive(Serialize)]
+struct SaveFile<'a> {
+    version: u32,
+    game: &'a Game,
+}
+
+#[derive(Deserialize)]
+struct SaveHeader {
+    version: u32,
+}
+
+#[derive(Deserialize)]
+struct LoadFile {
+    game: Game,
+}
+
+impl Game {
+    /// Serializes the full game state, including the RNG, into a human-readable JSON save.
+    pub fn to_json(&self) -> Result<String, SaveError> {
+        Ok(serde_json::to_string(&SaveFile {
+            version: SAVE_VERSION,
+            game: self,
+        })?)
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, SaveError> {
+        let header: SaveHeader = serde_json::from_str(json)?;
+        Self::check_version(header.version)?;
+        let file: LoadFile = serde_json::from_str(json)?;
+        Ok(file.game.restored())
+    }
+
+    /// Serializes the full game state, including the RNG, into a compact binary save.
+    /// The format version is always written first, so it can be checked before the rest is decoded.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, SaveError> {
+        let mut bytes = postcard::to_allocvec(&SAVE_VERSION)?;
+        bytes.extend(postcard::to_allocvec(self)?);
+        Ok(bytes)
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, SaveError> {
+        let (version, rest) = postcard::take_from_bytes::<u32>(bytes)?;
+        Self::check_version(version)?;
+        Ok(postcard::from_bytes::<Self>(rest)?.restored())
+    }
+
+    /// Reserves the tokens of the restored players, so new players can never be given one of them.
+    fn restored(self) -> Self {
+        for player in &self.players {
+            player.token().reserve();
+        }
+        self
+    }
+
+    fn check_version(version: u32) -> Result<(), SaveError> {
+        if version != SAVE_VERSION {
+            return Err(SaveError::UnsupportedVersion(version));
+        }
+        Ok(())
+    }
+}
+
+/// (De)serializes a `GameRng` through its full internal state (seed, stream and word position), so a restored game
+/// keeps drawing the exact same numbers as the original would have.
+pub(super) mod rng_state {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error};
+
+    use crate::game::GameRng;
+
+    /// `GameRng` does not implement `Clone`, so copies go through the same state it is saved with.
+    pub fn clone(rng: &GameRng) -> GameRng {
+        GameRng::deserialize_state(&rng.serialize_state())
+    }
+
+    pub fn serialize<S: Serializer>(rng: &GameRng, serializer: S) -> Result<S::Ok, S::Error> {
+        rng.serialize_state().as_slice().serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<GameRng, D::Error> {
+        let state = Vec::<u8>::deserialize(deserializer)?;
+        let state = state
+            .as_slice()
+            .try_into()
+            .map_err(|_| D::Error::invalid_length(state.len(), &"49 bytes of RNG state"))?;
+        Ok(GameRng::deserialize_state(state))
+    }
+}
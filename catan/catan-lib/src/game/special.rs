@@ -0,0 +1,77 @@
+use crate::{
+    game::{Game, event::GameEvent, player::OwnershipToken},
+    object::card::DevelopmentCardType,
+};
+
+/// The shortest road that can claim the Longest Road card.
+pub const LONGEST_ROAD_MINIMUM: u32 = 5;
+/// The fewest played robber cards that can claim the Largest Army card.
+pub const LARGEST_ARMY_MINIMUM: u32 = 3;
+
+impl Game {
+    pub fn longest_road_holder(&self) -> Option<OwnershipToken> {
+        self.longest_road
+    }
+
+    pub fn largest_army_holder(&self) -> Option<OwnershipToken> {
+        self.largest_army
+    }
+
+    /// Counts the robber cards the player has played.
+    pub fn army_size(&self, token: OwnershipToken) -> u32 {
+        self.find_player(token)
+            .development_cards()
+            .filter(|c| c.is_played() && c.get_type() == DevelopmentCardType::MoveRobber)
+            .count() as u32
+    }
+
+    /// Re-evaluates who holds Longest Road after any transport or building is placed, as buildings can cut roads.
+    ///
+    /// The holder keeps the card on a tie. If their road was cut, it passes to whoever is now strictly the longest, or
+    /// is set aside if that is a tie or nobody reaches the minimum.
+    pub(super) fn update_longest_road(&mut self) {
+        let lengths: Vec<(OwnershipToken, u32)> = self
+            .players
+            .iter()
+            .map(|p| (p.token(), self.calculate_longest_road(p.token())))
+            .collect();
+
+        let longest = lengths.iter().map(|(_, l)| *l).max().unwrap_or(0);
+
+        let holder_length = self
+            .longest_road
+            .and_then(|h| lengths.iter().find(|(t, _)| *t == h))
+            .map(|(_, l)| *l);
+
+        if holder_length.is_some_and(|l| l >= longest && l >= LONGEST_ROAD_MINIMUM) {
+            return;
+        }
+
+        let mut candidates = lengths
+            .iter()
+            .filter(|(_, l)| *l == longest && *l >= LONGEST_ROAD_MINIMUM);
+
+        let holder = match (candidates.next(), candidates.next()) {
+            (Some((token, _)), None) => Some(*token),
+            _ => None,
+        };
+
+        if holder != self.longest_road {
+            self.longest_road = holder;
+            self.emit(GameEvent::LongestRoadChanged { player: holder });
+        }
+    }
+
+    /// Re-evaluates who holds Largest Army after the player has played a robber card. The holder keeps the card
+    /// unless it is strictly surpassed.
+    pub(super) fn update_largest_army(&mut self, token: OwnershipToken) {
+        let size = self.army_size(token);
+
+        let holder_size = self.largest_army.map_or(0, |h| self.army_size(h));
+
+        if self.largest_army != Some(token) && size >= LARGEST_ARMY_MINIMUM && size > holder_size {
+            self.largest_army = Some(token);
+            self.emit(GameEvent::LargestArmyChanged { player: token });
+        }
+    }
+}
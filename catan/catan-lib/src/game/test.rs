@@ -1,25 +1,116 @@
-use std::num::NonZeroUsize;
+use std::{cell::Cell, num::NonZeroUsize, rc::Rc};
 
 use hexgrid::{
+    corner::position::{CornerHeight, CornerPosition},
     edge::position::{EdgeOrientation, EdgePosition},
-    hex::position::HexPosition,
+    hex::{iterators::spiral::HexSpiral, position::HexPosition},
 };
-use rand::SeedableRng;
+use rand::{SeedableRng, prelude::*};
 
 use crate::game::GameRng;
 
 use crate::{
-    game::{Game, edition},
+    bot::acting_player,
+    game::{
+        Game,
+        action::Action,
+        action::DevelopmentCardPlay,
+        bank::Bank,
+        edition::{self, GameEdition, StartingResources},
+        error::{
+            ActionError, BuildError, DevelopmentCardError, RobberError, TradeError, TurnError,
+        },
+        event::{EventQueue, EventSubscriber, GameEvent},
+        phase::{RobberStep, SetupRound, SetupStep, TurnPhase},
+        player::OwnershipToken,
+        replay::{ReplayError, ReplayLog},
+        save::{SAVE_VERSION, SaveError},
+        victory::VictoryPoints,
+    },
     object::{
-        card::ResourceMap,
-        resource::ResourceType,
+        TileType,
+        card::{DevelopmentCard, DevelopmentCardDistribution, DevelopmentCardType, ResourceMap},
+        resource::{RESOURCES, ResourceType},
         structure::{
-            OwnedStructures,
+            OwnedStructures, StructureType,
+            building::{Building, BuildingType},
             transport::{Transport, TransportType},
         },
+        trade::TradeType,
     },
 };
 
+/// Returns the next action needed to get the robber out of the way after a 7, so tests can skip ahead turns.
+fn robber_action(game: &Game) -> Option<(OwnershipToken, Action)> {
+    let current = game.current_turn();
+
+    match game.phase() {
+        TurnPhase::Robber(RobberStep::Discard) => {
+            let player = (0..)
+                .map_while(|i| game.get_player(i))
+                .map(|p| p.token())
+                .find(|p| game.pending_discard(*p).is_some())?;
+            let held = game.find_player(player).get_resources();
+            let mut discard = ResourceMap::empty();
+            for resource in RESOURCES {
+                let count = held
+                    .get(resource)
+                    .get_count()
+                    .min(game.pending_discard(player)? - discard.total());
+                discard.add_resource(resource, count);
+            }
+            Some((player, Action::Discard(discard)))
+        }
+        TurnPhase::Robber(RobberStep::Move) => HexSpiral::new(3, 5)
+            .find(|h| game.can_move_robber(current, *h).is_ok())
+            .map(|h| (current, Action::MoveRobber(h))),
+        TurnPhase::Robber(RobberStep::Steal(position)) => game
+            .robber_victims(current, position)
+            .next()
+            .map(|victim| (current, Action::Steal(victim))),
+        _ => None,
+    }
+}
+
+/// Places the starting settlements and roads of a two player game. The first player's buildings are on the top left
+/// corners of `ORIGIN` and `DOWN_LEFT + DOWN_RIGHT + RIGHT`, the second player's on `RIGHT + RIGHT` and
+/// `DOWN_LEFT + DOWN_LEFT`, each with a road on the top left edge of the same hex.
+fn place_setup(game: &mut Game) {
+    let player1 = game.get_player(0).unwrap().token();
+    let player2 = game.get_player(1).unwrap().token();
+
+    for (player, hex) in [
+        (player1, HexPosition::ORIGIN),
+        (player2, HexPosition::RIGHT + HexPosition::RIGHT),
+        (player2, HexPosition::DOWN_LEFT + HexPosition::DOWN_LEFT),
+        (
+            player1,
+            HexPosition::DOWN_LEFT + HexPosition::DOWN_RIGHT + HexPosition::RIGHT,
+        ),
+    ] {
+        game.apply(
+            player,
+            Action::BuildSettlement((hex + CornerHeight::TOP_LEFT).into()),
+        )
+        .unwrap();
+        game.apply(
+            player,
+            Action::BuildRoad((hex + EdgeOrientation::TOP_LEFT).into()),
+        )
+        .unwrap();
+    }
+}
+
+/// Rolls the dice for the current player, deals with the robber and ends the turn.
+fn pass_turn(game: &mut Game) {
+    let player = game.current_turn();
+    game.apply(player, Action::RollDice).unwrap();
+    while let Some((player, action)) = robber_action(game) {
+        game.apply(player, action).unwrap();
+    }
+    game.apply(player, Action::EndTurn).unwrap();
+}
+
 #[test]
 fn longest_road_test() {
     let mut rng = GameRng::seed_from_u64(1);
@@ -195,13 +286,41 @@ fn longest_road_test() {
     ];
 
     let player1 = game.get_player(0).unwrap().token();
-    game.find_player_mut(player1).add_resources(
-        ResourceMap::empty()
-            .with_resource(ResourceType::Brick, 100)
-            .with_resource(ResourceType::Wood, 100),
-    );
+    let player2 = game.get_player(1).unwrap().token();
+
+    for player in [player1, player2] {
+        game.find_player_mut(player)
+            .add_resources(ResourceMap::new(100, 100, 100, 100, 100));
+    }
+
+    let setup = [
+        (player1, roads[0].neighboring_corners()[0], roads[0]),
+        (
+            player2,
+            (HexPosition::ORIGIN + CornerHeight::TOP_LEFT).into(),
+            (HexPosition::ORIGIN + EdgeOrientation::TOP_LEFT).into(),
+        ),
+        (
+            player2,
+            ((HexPosition::RIGHT + HexPosition::RIGHT) + CornerHeight::TOP_LEFT).into(),
+            ((HexPosition::RIGHT + HexPosition::RIGHT) + EdgeOrientation::TOP_LEFT).into(),
+        ),
+        (player1, roads[12].neighboring_corners()[0], roads[12]),
+    ];
+
+    for (player, corner, edge) in setup {
+        game.play_building(Building::new(BuildingType::Settlement, player), corner)
+            .unwrap();
+        game.play_transport(Transport::new(TransportType::Road, player), edge)
+            .unwrap();
+    }
 
-    for road in roads {
+    game.roll_dice(player1).unwrap();
+
+    for road in roads
+        .into_iter()
+        .filter(|r| *r != roads[0] && *r != roads[12])
+    {
         game.play_transport(Transport::new(TransportType::Road, player1), road)
             .unwrap()
     }
@@ -211,4 +330,2020 @@ fn longest_road_test() {
     println!("The longest road was calculated to be {:}", longest_road);
 
     assert_eq!(longest_road, 15);
+    assert_eq!(game.longest_road_holder(), Some(player1));
+}
+
+#[test]
+fn turn_phase_test() {
+    let mut rng = GameRng::seed_from_u64(1);
+    let edition = edition::CustomEdition::of_size(3, 5, &mut rng).build();
+
+    let mut game = Game::new(edition, NonZeroUsize::new(2).unwrap(), &mut rng);
+
+    let player1 = game.get_player(0).unwrap().token();
+    let player2 = game.get_player(1).unwrap().token();
+
+    for player in [player1, player2] {
+        game.find_player_mut(player)
+            .add_resources(ResourceMap::new(10, 10, 10, 10, 10));
+    }
+
+    assert!(matches!(
+        game.roll_dice(player1),
+        Err(TurnError::WrongPhase { .. })
+    ));
+
+    let corner = (HexPosition::ORIGIN + CornerHeight::TOP_LEFT).into();
+    let edge: EdgePosition = (HexPosition::ORIGIN + EdgeOrientation::TOP_LEFT).into();
+
+    assert!(matches!(
+        game.play_building(Building::new(BuildingType::Settlement, player2), corner),
+        Err(BuildError::Turn(TurnError::NotPlayersTurn { .. }))
+    ));
+
+    assert!(matches!(
+        game.play_transport(Transport::new(TransportType::Road, player1), edge),
+        Err(BuildError::Turn(TurnError::WrongPhase { .. }))
+    ));
+
+    game.play_building(Building::new(BuildingType::Settlement, player1), corner)
+        .unwrap();
+
+    assert_eq!(
+        game.phase(),
+        TurnPhase::Setup {
+            round: SetupRound::First,
+            step: SetupStep::Transport(corner)
+        }
+    );
+
+    assert!(matches!(
+        game.play_transport(
+            Transport::new(TransportType::Road, player1),
+            (HexPosition::DOWN_RIGHT + EdgeOrientation::TOP_LEFT).into()
+        ),
+        Err(BuildError::SetupTransportMustTouchBuilding)
+    ));
+
+    game.play_transport(Transport::new(TransportType::Road, player1), edge)
+        .unwrap();
+
+    // snake order: player 2 places twice in a row, then player 1 finishes setup.
+    for (player, hex) in [
+        (player2, HexPosition::RIGHT + HexPosition::RIGHT),
+        (player2, HexPosition::DOWN_LEFT + HexPosition::DOWN_LEFT),
+        (
+            player1,
+            HexPosition::DOWN_LEFT + HexPosition::DOWN_RIGHT + HexPosition::RIGHT,
+        ),
+    ] {
+        assert_eq!(game.current_turn(), player);
+        game.play_building(
+            Building::new(BuildingType::Settlement, player),
+            (hex + CornerHeight::TOP_LEFT).into(),
+        )
+        .unwrap();
+        game.play_transport(
+            Transport::new(TransportType::Road, player),
+            (hex + EdgeOrientation::TOP_LEFT).into(),
+        )
+        .unwrap();
+    }
+
+    assert_eq!(game.current_turn(), player1);
+    assert_eq!(game.phase(), TurnPhase::Roll);
+
+    assert!(matches!(
+        game.end_turn(player1),
+        Err(TurnError::WrongPhase { .. })
+    ));
+
+    game.roll_dice(player1).unwrap();
+    assert_eq!(game.phase(), TurnPhase::Trade);
+
+    game.end_turn(player1).unwrap();
+    assert_eq!(game.current_turn(), player2);
+    assert_eq!(game.phase(), TurnPhase::Roll);
+}
+
+#[test]
+fn apply_action_test() {
+    let mut rng = GameRng::seed_from_u64(1);
+    let edition = edition::CustomEdition::of_size(3, 5, &mut rng).build();
+
+    let mut game = Game::new(edition, NonZeroUsize::new(1).unwrap(), &mut rng);
+
+    let player = game.get_player(0).unwrap().token();
+    game.find_player_mut(player)
+        .add_resources(ResourceMap::new(10, 10, 10, 10, 10));
+
+    let corner = (HexPosition::ORIGIN + CornerHeight::TOP_LEFT).into();
+    let edge = (HexPosition::ORIGIN + EdgeOrientation::TOP_LEFT).into();
+
+    let action = Action::BuildSettlement(corner);
+    let json = serde_json::to_string(&action).unwrap();
+    assert_eq!(serde_json::from_str::<Action>(&json).unwrap(), action);
+
+    let events = game.apply(player, action).unwrap();
+    assert_eq!(
+        events.first(),
+        Some(&GameEvent::BuildingPlaced {
+            building: Building::new(BuildingType::Settlement, player),
+            position: corner,
+        })
+    );
+
+    assert!(matches!(
+        game.apply(player, Action::BuildSettlement(corner)),
+        Err(ActionError::Build(BuildError::Turn(
+            TurnError::WrongPhase { .. }
+        )))
+    ));
+
+    let events = game.apply(player, Action::BuildRoad(edge)).unwrap();
+    assert!(events.contains(&GameEvent::TransportPlaced {
+        transport: Transport::new(TransportType::Road, player),
+        position: edge,
+    }));
+
+    let hex = HexPosition::DOWN_LEFT + HexPosition::DOWN_RIGHT;
+    game.apply(
+        player,
+        Action::BuildSettlement((hex + CornerHeight::TOP_LEFT).into()),
+    )
+    .unwrap();
+    let events = game
+        .apply(
+            player,
+            Action::BuildRoad((hex + EdgeOrientation::TOP_LEFT).into()),
+        )
+        .unwrap();
+    assert_eq!(
+        events.last(),
+        Some(&GameEvent::PhaseChanged {
+            phase: TurnPhase::Roll
+        })
+    );
+
+    let events = game.apply(player, Action::RollDice).unwrap();
+    assert!(matches!(events.first(), Some(GameEvent::DiceRolled { .. })));
+
+    let events = game.apply(player, Action::EndTurn).unwrap();
+    assert_eq!(events.first(), Some(&GameEvent::TurnEnded { player }));
+}
+
+#[test]
+fn off_board_action_test() {
+    let mut rng = GameRng::seed_from_u64(1);
+    let edition = edition::CustomEdition::of_size(3, 5, &mut rng).build();
+
+    let mut game = Game::new(edition, NonZeroUsize::new(1).unwrap(), &mut rng);
+    let player = game.get_player(0).unwrap().token();
+
+    // Coordinates which lie between corners, as anyone could send over the WASM interface.
+    assert!(
+        serde_json::from_str::<Action>(r#"{"BuildSettlement":{"High":{"rights":0,"downs":0}}}"#)
+            .is_err()
+    );
+    assert!(
+        serde_json::from_str::<Action>(
+            r#"{"MoveRobber":{"rights":2147483647,"downs":2147483647}}"#
+        )
+        .is_err()
+    );
+
+    let far: HexPosition = HexPosition::ORIGIN + HexPosition::UP_LEFT * 3;
+    let corner: CornerPosition = (far + CornerHeight::TOP_LEFT).into();
+    let edge: EdgePosition = (far + EdgeOrientation::TOP_LEFT).into();
+
+    assert!(matches!(
+        game.apply(player, Action::BuildSettlement(corner)),
+        Err(ActionError::CornerNotOnBoard(p)) if p == corner
+    ));
+    assert!(matches!(
+        game.apply(player, Action::BuildRoad(edge)),
+        Err(ActionError::EdgeNotOnBoard(p)) if p == edge
+    ));
+    assert!(matches!(
+        game.apply(player, Action::MoveRobber(far)),
+        Err(ActionError::HexNotOnBoard(p)) if p == far
+    ));
+
+    assert_eq!(
+        game.phase(),
+        TurnPhase::Setup {
+            round: SetupRound::First,
+            step: SetupStep::Building
+        }
+    );
+    assert_eq!(game.board.buildings().count(), 0);
+}
+
+#[test]
+fn resource_map_deserialization_test() {
+    let resources = ResourceMap::new(3, 0, 1, 0, 2);
+    let json = serde_json::to_string(&resources).unwrap();
+    assert_eq!(json, r#"{"wood":3,"brick":0,"wheat":1,"sheep":0,"ore":2}"#);
+    assert_eq!(
+        serde_json::from_str::<ResourceMap>(&json).unwrap(),
+        resources
+    );
+
+    // Cards can no longer be read under the field of another resource.
+    assert!(
+        serde_json::from_str::<ResourceMap>(
+            r#"{"wood":{"resource_type":"Ore","count":3},"brick":0,"wheat":0,"sheep":0,"ore":0}"#
+        )
+        .is_err()
+    );
+
+    let huge: ResourceMap =
+        serde_json::from_str(r#"{"wood":4294967295,"brick":1,"wheat":0,"sheep":0,"ore":0}"#)
+            .unwrap();
+    assert_eq!(huge.total(), u32::MAX);
+    assert_eq!(huge.checked_total(), None);
+    assert_eq!((huge + huge).get(ResourceType::Wood).get_count(), u32::MAX);
+
+    let mut rng = GameRng::seed_from_u64(1);
+    let edition = edition::CustomEdition::of_size(3, 5, &mut rng).build();
+    let mut game = Game::new(edition, NonZeroUsize::new(2).unwrap(), &mut rng);
+    let player = game.get_player(0).unwrap().token();
+
+    for action in [
+        Action::Discard(huge),
+        Action::TradeWithBank {
+            give: ResourceMap::new(4, 0, 0, 0, 0),
+            receive: huge,
+        },
+        Action::ProposeTrade {
+            to: None,
+            give: huge,
+            receive: ResourceMap::new(0, 1, 0, 0, 0),
+        },
+    ] {
+        assert!(matches!(
+            game.apply(player, action),
+            Err(ActionError::TooManyResources(r)) if r == huge
+        ));
+    }
+}
+
+#[test]
+fn save_restore_test() {
+    let mut rng = GameRng::seed_from_u64(1);
+    let edition = edition::CustomEdition::of_size(3, 5, &mut rng).build();
+
+    let mut game = Game::new(edition, NonZeroUsize::new(1).unwrap(), &mut rng);
+
+    let player = game.get_player(0).unwrap().token();
+    game.find_player_mut(player)
+        .add_resources(ResourceMap::new(10, 10, 10, 10, 10));
+
+    let hex = HexPosition::DOWN_LEFT + HexPosition::DOWN_RIGHT;
+    for (corner, edge) in [
+        (
+            HexPosition::ORIGIN + CornerHeight::TOP_LEFT,
+            HexPosition::ORIGIN + EdgeOrientation::TOP_LEFT,
+        ),
+        (
+            hex + CornerHeight::TOP_LEFT,
+            hex + EdgeOrientation::TOP_LEFT,
+        ),
+    ] {
+        game.apply(player, Action::BuildSettlement(corner.into()))
+            .unwrap();
+        game.apply(player, Action::BuildRoad(edge.into())).unwrap();
+    }
+    game.apply(player, Action::RollDice).unwrap();
+    while let Some((player, action)) = robber_action(&game) {
+        game.apply(player, action).unwrap();
+    }
+
+    let json = game.to_json().unwrap();
+    let bytes = game.to_bytes().unwrap();
+    let mut from_json = Game::from_json(&json).unwrap();
+    let mut from_bytes = Game::from_bytes(&bytes).unwrap();
+
+    assert_eq!(from_json.to_json().unwrap(), json);
+    assert_eq!(from_bytes.to_bytes().unwrap(), bytes);
+    assert_eq!(from_bytes.to_json().unwrap(), json);
+    assert_eq!(from_json.phase(), TurnPhase::Trade);
+    assert_eq!(from_json.current_turn(), player);
+
+    // the restored RNG must continue exactly where the original left off
+    for _ in 0..5 {
+        let expected = [&mut game, &mut from_json, &mut from_bytes].map(|game| {
+            game.apply(player, Action::EndTurn).unwrap();
+            let mut events = game.apply(player, Action::RollDice).unwrap();
+            while let Some((player, action)) = robber_action(game) {
+                events.extend(game.apply(player, action).unwrap());
+            }
+            events
+        });
+        assert_eq!(expected[0], expected[1]);
+        assert_eq!(expected[0], expected[2]);
+    }
+
+    let outdated = json.replacen(&format!("\"version\":{SAVE_VERSION}"), "\"version\":0", 1);
+    assert!(matches!(
+        Game::from_json(&outdated),
+        Err(SaveError::UnsupportedVersion(0))
+    ));
+}
+
+#[test]
+fn restored_token_test() {
+    // tokens in untrusted input like actions must not move the counter
+    let untrusted: OwnershipToken = serde_json::from_str(&u64::MAX.to_string()).unwrap();
+    assert_ne!(OwnershipToken::new(), untrusted);
+
+    let mut rng = GameRng::seed_from_u64(1);
+    let edition = edition::CustomEdition::of_size(3, 5, &mut rng).build();
+    let game = Game::new(edition, NonZeroUsize::new(1).unwrap(), &mut rng);
+
+    // a token just ahead of the counter, as if it was saved by a process which had created more players
+    let ahead = serde_json::to_value(OwnershipToken::new())
+        .unwrap()
+        .as_u64()
+        .unwrap()
+        + 1;
+    let mut save: serde_json::Value = serde_json::from_str(&game.to_json().unwrap()).unwrap();
+    save["game"]["players"][0]["token"] = ahead.into();
+    save["game"]["current_turn"] = ahead.into();
+    let restored = Game::from_json(&save.to_string()).unwrap();
+    let restored = restored.get_player(0).unwrap().token();
+
+    assert_ne!(OwnershipToken::new(), restored);
+    assert_ne!(OwnershipToken::new(), restored);
+}
+
+#[test]
+fn replay_test() {
+    let mut rng = GameRng::seed_from_u64(1);
+    let edition = edition::CustomEdition::of_size(3, 5, &mut rng).build();
+
+    let mut game = Game::new(edition, NonZeroUsize::new(1).unwrap(), &mut rng);
+
+    let player = game.get_player(0).unwrap().token();
+    game.find_player_mut(player)
+        .add_resources(ResourceMap::new(10, 10, 10, 10, 10));
+    let mut log = ReplayLog::new(&game).unwrap();
+
+    let hex = HexPosition::DOWN_LEFT + HexPosition::DOWN_RIGHT;
+    for (corner, edge) in [
+        (
+            HexPosition::ORIGIN + CornerHeight::TOP_LEFT,
+            HexPosition::ORIGIN + EdgeOrientation::TOP_LEFT,
+        ),
+        (
+            hex + CornerHeight::TOP_LEFT,
+            hex + EdgeOrientation::TOP_LEFT,
+        ),
+    ] {
+        log.apply(&mut game, player, Action::BuildSettlement(corner.into()))
+            .unwrap();
+        log.apply(&mut game, player, Action::BuildRoad(edge.into()))
+            .unwrap();
+    }
+
+    // rejected actions are not recorded
+    assert!(log.apply(&mut game, player, Action::EndTurn).is_err());
+
+    let mut snapshots = Vec::new();
+    for _ in 0..5 {
+        log.apply(&mut game, player, Action::RollDice).unwrap();
+        while let Some((player, action)) = robber_action(&game) {
+            log.apply(&mut game, player, action).unwrap();
+        }
+        log.apply(&mut game, player, Action::EndTurn).unwrap();
+        snapshots.push((game.turn_number(), game.state_hash()));
+    }
+
+    assert_eq!(log.replay().unwrap().state_hash(), game.state_hash());
+
+    for (turn_number, hash) in snapshots {
+        let replayed = log.replay_to_turn(turn_number).unwrap();
+        assert_eq!(replayed.turn_number(), turn_number);
+        assert_eq!(replayed.state_hash(), hash);
+    }
+
+    assert!(matches!(
+        log.replay_to_turn(100),
+        Err(ReplayError::TurnNotReached(100))
+    ));
+
+    // a log shared as JSON replays identically, and tampering with it is detected
+    let mut json = serde_json::to_value(&log).unwrap();
+    let restored: ReplayLog = serde_json::from_value(json.clone()).unwrap();
+    assert_eq!(restored.replay().unwrap().state_hash(), game.state_hash());
+
+    json["entries"][6]["state_hash"] = serde_json::json!(0);
+    let tampered: ReplayLog = serde_json::from_value(json).unwrap();
+    assert!(matches!(
+        tampered.replay(),
+        Err(ReplayError::Divergence { index: 6, .. })
+    ));
+
+    // a truncated log keeps checkpoints which point past its last entry
+    let mut json = serde_json::to_value(&log).unwrap();
+    json["entries"].as_array_mut().unwrap().truncate(3);
+    let truncated: ReplayLog = serde_json::from_value(json).unwrap();
+    let last = log.checkpoints().last().unwrap();
+    assert!(matches!(
+        truncated.replay_to_turn(last.turn_number),
+        Err(ReplayError::MissingEntries { len: 3, end }) if end == last.action_index
+    ));
+}
+
+#[test]
+fn robber_test() {
+    let mut rng = GameRng::seed_from_u64(1);
+    let edition = edition::CustomEdition::of_size(3, 5, &mut rng).build();
+
+    let mut game = Game::new(edition, NonZeroUsize::new(2).unwrap(), &mut rng);
+
+    let player1 = game.get_player(0).unwrap().token();
+    let player2 = game.get_player(1).unwrap().token();
+
+    for player in [player1, player2] {
+        game.find_player_mut(player)
+            .add_resources(ResourceMap::new(10, 10, 10, 10, 10));
+    }
+
+    place_setup(&mut game);
+
+    // play until somebody rolls a 7
+    let thief = loop {
+        let player = game.current_turn();
+        game.apply(player, Action::RollDice).unwrap();
+        if let TurnPhase::Robber(_) = game.phase() {
+            break player;
+        }
+        game.apply(player, Action::EndTurn).unwrap();
+    };
+    let victim = if thief == player1 { player2 } else { player1 };
+
+    assert_eq!(game.phase(), TurnPhase::Robber(RobberStep::Discard));
+    assert!(matches!(
+        game.apply(thief, Action::EndTurn),
+        Err(ActionError::Turn(TurnError::WrongPhase { .. }))
+    ));
+
+    for player in [player1, player2] {
+        let held = game.find_player(player).get_resources();
+        let expected = game.pending_discard(player).unwrap();
+        assert_eq!(expected, held.total() / 2);
+
+        assert!(matches!(
+            game.apply(player, Action::Discard(ResourceMap::new(1, 0, 0, 0, 0))),
+            Err(ActionError::Robber(RobberError::WrongDiscardCount { .. }))
+        ));
+
+        let mut discard = ResourceMap::empty();
+        for resource in RESOURCES {
+            let count = held
+                .get(resource)
+                .get_count()
+                .min(expected - discard.total());
+            discard.add_resource(resource, count);
+        }
+
+        let events = game.apply(player, Action::Discard(discard)).unwrap();
+        assert_eq!(
+            events.first(),
+            Some(&GameEvent::CardsDiscarded {
+                player,
+                resources: discard
+            })
+        );
+        assert_eq!(
+            game.find_player(player).get_resources().total(),
+            held.total() - expected
+        );
+    }
+
+    assert_eq!(game.phase(), TurnPhase::Robber(RobberStep::Move));
+    assert!(matches!(
+        game.apply(thief, Action::Discard(ResourceMap::empty())),
+        Err(ActionError::Robber(RobberError::Turn(
+            TurnError::WrongPhase { .. }
+        )))
+    ));
+    assert!(matches!(
+        game.apply(victim, Action::MoveRobber(HexPosition::ORIGIN)),
+        Err(ActionError::Robber(RobberError::Turn(
+            TurnError::NotPlayersTurn { .. }
+        )))
+    ));
+    assert!(matches!(
+        game.apply(thief, Action::MoveRobber(game.get_robber())),
+        Err(ActionError::Robber(RobberError::SameHex))
+    ));
+    let water = game
+        .board
+        .hex_positions()
+        .find(|p| game.get_tile_type(*p) == TileType::Water)
+        .unwrap();
+    assert!(matches!(
+        game.apply(thief, Action::MoveRobber(water)),
+        Err(ActionError::Robber(RobberError::NotLand { .. }))
+    ));
+    let outside = game.get_offset() + HexPosition::LEFT;
+    assert!(matches!(
+        game.apply(thief, Action::MoveRobber(outside)),
+        Err(ActionError::HexNotOnBoard(_))
+    ));
+
+    // a land hex next to the victim's first settlement
+    let settlement = if victim == player1 {
+        HexPosition::ORIGIN
+    } else {
+        HexPosition::RIGHT + HexPosition::RIGHT
+    };
+    let corner: CornerPosition = (settlement + CornerHeight::TOP_LEFT).into();
+    let target = corner
+        .neighboring_hex()
+        .into_iter()
+        .find(|p| *p != game.get_robber() && game.get_tile_type(*p) != TileType::Water)
+        .unwrap();
+
+    let events = game.apply(thief, Action::MoveRobber(target)).unwrap();
+    assert_eq!(
+        events.first(),
+        Some(&GameEvent::RobberMoved {
+            player: thief,
+            position: target
+        })
+    );
+    assert_eq!(game.get_robber(), target);
+    assert_eq!(game.phase(), TurnPhase::Robber(RobberStep::Steal(target)));
+
+    assert!(matches!(
+        game.apply(thief, Action::Steal(thief)),
+        Err(ActionError::Robber(RobberError::InvalidVictim { .. }))
+    ));
+
+    let thief_cards = game.find_player(thief).get_resources().total();
+    let victim_cards = game.find_player(victim).get_resources().total();
+
+    let events = game.apply(thief, Action::Steal(victim)).unwrap();
+    assert!(matches!(
+        events.first(),
+        Some(GameEvent::CardStolen { thief: t, victim: v, .. }) if *t == thief && *v == victim
+    ));
+    assert_eq!(
+        game.find_player(thief).get_resources().total(),
+        thief_cards + 1
+    );
+    assert_eq!(
+        game.find_player(victim).get_resources().total(),
+        victim_cards - 1
+    );
+    assert_eq!(game.phase(), TurnPhase::Trade);
+}
+
+#[test]
+fn development_card_test() {
+    let mut rng = GameRng::seed_from_u64(1);
+    let edition = edition::CustomEdition::of_size(3, 5, &mut rng)
+        .with_development_card_distribution(DevelopmentCardDistribution::new([
+            (DevelopmentCardType::MoveRobber, 1),
+            (DevelopmentCardType::TakeTwoResources, 1),
+            (DevelopmentCardType::Monopoly, 1),
+            (DevelopmentCardType::VictoryPoint, 1),
+            (DevelopmentCardType::BuildRoads, 1),
+        ]))
+        .build();
+
+    let mut game = Game::new(edition, NonZeroUsize::new(2).unwrap(), &mut rng);
+
+    let player1 = game.get_player(0).unwrap().token();
+    let player2 = game.get_player(1).unwrap().token();
+
+    for player in [player1, player2] {
+        game.find_player_mut(player)
+            .add_resources(ResourceMap::new(10, 10, 10, 10, 10));
+    }
+
+    place_setup(&mut game);
+
+    assert!(matches!(
+        game.apply(player1, Action::BuyDevelopmentCard),
+        Err(ActionError::DevelopmentCard(DevelopmentCardError::Turn(
+            TurnError::WrongPhase { .. }
+        )))
+    ));
+
+    game.apply(player1, Action::RollDice).unwrap();
+    while let Some((player, action)) = robber_action(&game) {
+        game.apply(player, action).unwrap();
+    }
+
+    for _ in 0..5 {
+        let held = game.find_player(player1).get_resources();
+        let events = game.apply(player1, Action::BuyDevelopmentCard).unwrap();
+        assert_eq!(
+            events.first(),
+            Some(&GameEvent::DevelopmentCardBought { player: player1 })
+        );
+        assert_eq!(
+            game.find_player(player1).get_resources(),
+            held - ResourceMap::new(0, 0, 1, 1, 1)
+        );
+    }
+    assert_eq!(game.development_cards_left(), 0);
+    assert!(matches!(
+        game.apply(player1, Action::BuyDevelopmentCard),
+        Err(ActionError::DevelopmentCard(
+            DevelopmentCardError::EmptyDeck
+        ))
+    ));
+
+    assert!(matches!(
+        game.apply(
+            player1,
+            Action::PlayDevelopmentCard(DevelopmentCardPlay::MoveRobber)
+        ),
+        Err(ActionError::DevelopmentCard(
+            DevelopmentCardError::BoughtThisTurn
+        ))
+    ));
+
+    // victory points can be revealed right away, and do not count towards the one card per turn
+    game.apply(
+        player1,
+        Action::PlayDevelopmentCard(DevelopmentCardPlay::VictoryPoint),
+    )
+    .unwrap();
+    assert!(matches!(
+        game.apply(
+            player1,
+            Action::PlayDevelopmentCard(DevelopmentCardPlay::VictoryPoint)
+        ),
+        Err(ActionError::DevelopmentCard(DevelopmentCardError::NoCard(
+            DevelopmentCardType::VictoryPoint
+        )))
+    ));
+
+    game.apply(player1, Action::EndTurn).unwrap();
+    assert!(matches!(
+        game.apply(
+            player2,
+            Action::PlayDevelopmentCard(DevelopmentCardPlay::Monopoly(ResourceType::Wood))
+        ),
+        Err(ActionError::DevelopmentCard(DevelopmentCardError::NoCard(
+            DevelopmentCardType::Monopoly
+        )))
+    ));
+    pass_turn(&mut game);
+
+    // cards can be played before rolling
+    let wood = game.find_player(player1).count_resource(ResourceType::Wood)
+        + game.find_player(player2).count_resource(ResourceType::Wood);
+    game.apply(
+        player1,
+        Action::PlayDevelopmentCard(DevelopmentCardPlay::Monopoly(ResourceType::Wood)),
+    )
+    .unwrap();
+    assert_eq!(
+        game.find_player(player1).count_resource(ResourceType::Wood),
+        wood
+    );
+    assert_eq!(
+        game.find_player(player2).count_resource(ResourceType::Wood),
+        0
+    );
+    assert!(matches!(
+        game.apply(
+            player1,
+            Action::PlayDevelopmentCard(DevelopmentCardPlay::TakeTwoResources(
+                ResourceType::Ore,
+                ResourceType::Ore
+            ))
+        ),
+        Err(ActionError::DevelopmentCard(
+            DevelopmentCardError::AlreadyPlayedThisTurn
+        ))
+    ));
+    pass_turn(&mut game);
+    pass_turn(&mut game);
+
+    let ore = game.find_player(player1).count_resource(ResourceType::Ore);
+    game.apply(
+        player1,
+        Action::PlayDevelopmentCard(DevelopmentCardPlay::TakeTwoResources(
+            ResourceType::Ore,
+            ResourceType::Ore,
+        )),
+    )
+    .unwrap();
+    assert_eq!(
+        game.find_player(player1).count_resource(ResourceType::Ore),
+        ore + 2
+    );
+    pass_turn(&mut game);
+    pass_turn(&mut game);
+
+    game.apply(
+        player1,
+        Action::PlayDevelopmentCard(DevelopmentCardPlay::BuildRoads),
+    )
+    .unwrap();
+    assert_eq!(game.phase(), TurnPhase::RoadBuilding { remaining: 2 });
+    assert!(matches!(
+        game.apply(player1, Action::RollDice),
+        Err(ActionError::Turn(TurnError::WrongPhase { .. }))
+    ));
+
+    let road = Transport::new(TransportType::Road, player1);
+    let mut last: EdgePosition = (HexPosition::ORIGIN + EdgeOrientation::TOP_LEFT).into();
+    for _ in 0..2 {
+        last = last
+            .neighboring_edges()
+            .into_iter()
+            .find(|e| game.can_play_transport(road, *e).is_ok())
+            .unwrap();
+        game.apply(player1, Action::BuildRoad(last)).unwrap();
+    }
+    assert_eq!(game.phase(), TurnPhase::Roll);
+    pass_turn(&mut game);
+    pass_turn(&mut game);
+
+    game.apply(
+        player1,
+        Action::PlayDevelopmentCard(DevelopmentCardPlay::MoveRobber),
+    )
+    .unwrap();
+    assert_eq!(game.phase(), TurnPhase::Robber(RobberStep::Move));
+    while let Some((player, action)) = robber_action(&game) {
+        game.apply(player, action).unwrap();
+    }
+    assert_eq!(game.phase(), TurnPhase::Roll);
+
+    assert!(
+        game.find_player(player1)
+            .development_cards()
+            .all(|c| c.is_played())
+    );
+}
+
+#[test]
+fn road_building_test() {
+    let mut rng = GameRng::seed_from_u64(1);
+    let edition = edition::CustomEdition::of_size(3, 5, &mut rng)
+        .with_development_card_distribution(DevelopmentCardDistribution::new([
+            (DevelopmentCardType::MoveRobber, 0),
+            (DevelopmentCardType::TakeTwoResources, 0),
+            (DevelopmentCardType::Monopoly, 0),
+            (DevelopmentCardType::VictoryPoint, 0),
+            (DevelopmentCardType::BuildRoads, 2),
+        ]))
+        .with_owned_structures(OwnedStructures::new(5, 4, 3, 15))
+        .build();
+
+    let mut game = Game::new(edition, NonZeroUsize::new(2).unwrap(), &mut rng);
+
+    let player1 = game.get_player(0).unwrap().token();
+    let player2 = game.get_player(1).unwrap().token();
+
+    game.find_player_mut(player1)
+        .add_resources(ResourceMap::new(10, 10, 10, 10, 10));
+
+    // the first player only settles inland, where boats cannot go
+    for (player, hex) in [
+        (
+            player1,
+            HexPosition::DOWN_LEFT + HexPosition::DOWN_RIGHT + HexPosition::RIGHT,
+        ),
+        (player2, HexPosition::RIGHT + HexPosition::RIGHT),
+        (player2, HexPosition::DOWN_LEFT + HexPosition::DOWN_LEFT),
+        (
+            player1,
+            HexPosition::DOWN_LEFT + HexPosition::DOWN_RIGHT * 2 + HexPosition::RIGHT * 2,
+        ),
+    ] {
+        game.apply(
+            player,
+            Action::BuildSettlement((hex + CornerHeight::TOP_LEFT).into()),
+        )
+        .unwrap();
+        game.apply(
+            player,
+            Action::BuildRoad((hex + EdgeOrientation::TOP_LEFT).into()),
+        )
+        .unwrap();
+    }
+
+    game.apply(player1, Action::RollDice).unwrap();
+    while let Some((player, action)) = robber_action(&game) {
+        game.apply(player, action).unwrap();
+    }
+    for _ in 0..2 {
+        game.apply(player1, Action::BuyDevelopmentCard).unwrap();
+    }
+    game.apply(player1, Action::EndTurn).unwrap();
+    pass_turn(&mut game);
+
+    // a single road is left after setup, and boats can only follow it if it reaches the coast
+    game.apply(
+        player1,
+        Action::PlayDevelopmentCard(DevelopmentCardPlay::BuildRoads),
+    )
+    .unwrap();
+    assert_eq!(game.phase(), TurnPhase::RoadBuilding { remaining: 2 });
+
+    // a road which stays inland leaves nowhere for the second transport, so road building ends early
+    let road = game
+        .legal_actions(player1)
+        .into_iter()
+        .filter(|a| matches!(a, Action::BuildRoad(_)))
+        .find(|a| {
+            let mut game = game.clone();
+            game.apply(player1, *a).unwrap();
+            game.phase() == TurnPhase::Roll
+        })
+        .unwrap();
+    game.apply(player1, road).unwrap();
+    assert_eq!(game.phase(), TurnPhase::Roll);
+    pass_turn(&mut game);
+    pass_turn(&mut game);
+
+    // now the network is boxed in, so the card is spent without entering road building
+    game.apply(
+        player1,
+        Action::PlayDevelopmentCard(DevelopmentCardPlay::BuildRoads),
+    )
+    .unwrap();
+    assert_eq!(game.phase(), TurnPhase::Roll);
+    assert!(
+        game.find_player(player1)
+            .development_cards()
+            .all(|c| c.is_played())
+    );
+}
+
+#[test]
+fn largest_army_test() {
+    let mut rng = GameRng::seed_from_u64(1);
+    let edition = edition::CustomEdition::of_size(3, 5, &mut rng)
+        .with_development_card_distribution(DevelopmentCardDistribution::new([
+            (DevelopmentCardType::MoveRobber, 7),
+            (DevelopmentCardType::TakeTwoResources, 0),
+            (DevelopmentCardType::Monopoly, 0),
+            (DevelopmentCardType::VictoryPoint, 0),
+            (DevelopmentCardType::BuildRoads, 0),
+        ]))
+        .build();
+
+    let mut game = Game::new(edition, NonZeroUsize::new(2).unwrap(), &mut rng);
+
+    let player1 = game.get_player(0).unwrap().token();
+    let player2 = game.get_player(1).unwrap().token();
+
+    for player in [player1, player2] {
+        game.find_player_mut(player)
+            .add_resources(ResourceMap::new(10, 10, 10, 10, 10));
+    }
+
+    place_setup(&mut game);
+
+    // the first player buys three cards, the second player four
+    for count in [3, 4] {
+        let player = game.current_turn();
+        game.apply(player, Action::RollDice).unwrap();
+        while let Some((player, action)) = robber_action(&game) {
+            game.apply(player, action).unwrap();
+        }
+        for _ in 0..count {
+            game.apply(player, Action::BuyDevelopmentCard).unwrap();
+        }
+        game.apply(player, Action::EndTurn).unwrap();
+    }
+
+    let mut changes = Vec::new();
+    for _ in 0..4 {
+        for player in [player1, player2] {
+            let Ok(mut events) = game.apply(
+                player,
+                Action::PlayDevelopmentCard(DevelopmentCardPlay::MoveRobber),
+            ) else {
+                pass_turn(&mut game);
+                continue;
+            };
+            while let Some((player, action)) = robber_action(&game) {
+                events.extend(game.apply(player, action).unwrap());
+            }
+            changes.extend(
+                events
+                    .into_iter()
+                    .filter(|e| matches!(e, GameEvent::LargestArmyChanged { .. })),
+            );
+            pass_turn(&mut game);
+        }
+    }
+
+    assert_eq!(game.army_size(player1), 3);
+    assert_eq!(game.army_size(player2), 4);
+    assert_eq!(
+        changes,
+        [
+            GameEvent::LargestArmyChanged { player: player1 },
+            GameEvent::LargestArmyChanged { player: player2 },
+        ]
+    );
+    assert_eq!(game.largest_army_holder(), Some(player2));
+}
+
+#[test]
+fn victory_test() {
+    let mut rng = GameRng::seed_from_u64(1);
+
+    assert_eq!(edition::BaseEdition.get_victory_point_target(), 10);
+    assert_eq!(edition::ExpansionEdition.get_victory_point_target(), 12);
+    assert_eq!(
+        edition::CustomEdition::of_size(3, 6, &mut rng)
+            .build()
+            .get_victory_point_target(),
+        12
+    );
+
+    let edition = edition::CustomEdition::of_size(3, 5, &mut rng)
+        .with_development_card_distribution(DevelopmentCardDistribution::new([
+            (DevelopmentCardType::MoveRobber, 0),
+            (DevelopmentCardType::TakeTwoResources, 0),
+            (DevelopmentCardType::Monopoly, 0),
+            (DevelopmentCardType::VictoryPoint, 5),
+            (DevelopmentCardType::BuildRoads, 0),
+        ]))
+        .with_victory_point_target(4)
+        .build();
+    assert_eq!(edition.get_victory_point_target(), 4);
+
+    let mut game = Game::new(edition, NonZeroUsize::new(2).unwrap(), &mut rng);
+
+    let player1 = game.get_player(0).unwrap().token();
+    let player2 = game.get_player(1).unwrap().token();
+
+    for player in [player1, player2] {
+        game.find_player_mut(player)
+            .add_resources(ResourceMap::new(10, 10, 10, 10, 10));
+    }
+
+    place_setup(&mut game);
+    assert_eq!(game.victory_points(player1).total(), 2);
+
+    game.apply(player1, Action::RollDice).unwrap();
+    while let Some((player, action)) = robber_action(&game) {
+        game.apply(player, action).unwrap();
+    }
+
+    game.apply(player1, Action::BuyDevelopmentCard).unwrap();
+    game.apply(
+        player1,
+        Action::PlayDevelopmentCard(DevelopmentCardPlay::VictoryPoint),
+    )
+    .unwrap();
+    assert_eq!(game.winner(), None);
+
+    let events = game.apply(player1, Action::BuyDevelopmentCard).unwrap();
+    assert_eq!(events.last(), Some(&GameEvent::GameWon { player: player1 }));
+    assert_eq!(game.winner(), Some(player1));
+    assert_eq!(
+        game.victory_points(player1),
+        VictoryPoints {
+            settlements: 2,
+            revealed_cards: 1,
+            hidden_cards: 1,
+            ..Default::default()
+        }
+    );
+    assert_eq!(game.victory_points(player1).public(), 3);
+
+    assert!(matches!(
+        game.apply(player1, Action::EndTurn),
+        Err(ActionError::Turn(TurnError::WrongPhase {
+            phase: TurnPhase::GameOver { .. }
+        }))
+    ));
+    assert!(game.apply(player2, Action::RollDice).is_err());
+}
+
+/// Checks that consecutive transports share a corner without doubling back, and that every corner passed through is
+/// free of other players' buildings and only switches between roads and boats at one of the owner's buildings.
+#[test]
+fn trade_test() {
+    let mut rng = GameRng::seed_from_u64(3);
+    let mut game = Game::new(
+        edition::BaseEdition,
+        NonZeroUsize::new(2).unwrap(),
+        &mut rng,
+    );
+
+    let player1 = game.get_player(0).unwrap().token();
+    let player2 = game.get_player(1).unwrap().token();
+
+    for player in [player1, player2] {
+        game.find_player_mut(player)
+            .add_resources(ResourceMap::new(10, 10, 10, 10, 10));
+    }
+
+    place_setup(&mut game);
+
+    let wood = ResourceMap::new(1, 0, 0, 0, 0);
+    let ore = ResourceMap::new(0, 0, 0, 0, 1);
+    let propose = |to, give, receive| Action::ProposeTrade { to, give, receive };
+
+    // Trading only starts once the dice have been rolled.
+    assert!(matches!(
+        game.apply(player1, propose(Some(player2), wood, ore)),
+        Err(ActionError::Trade(TradeError::Turn(
+            TurnError::WrongPhase { .. }
+        )))
+    ));
+
+    game.apply(player1, Action::RollDice).unwrap();
+    while let Some((player, action)) = robber_action(&game) {
+        game.apply(player, action).unwrap();
+    }
+    assert_eq!(game.phase(), TurnPhase::Trade);
+
+    assert!(matches!(
+        game.apply(player2, propose(Some(player1), wood, ore)),
+        Err(ActionError::Trade(TradeError::Turn(
+            TurnError::NotPlayersTurn { .. }
+        )))
+    ));
+    assert!(matches!(
+        game.apply(player1, propose(Some(player1), wood, ore)),
+        Err(ActionError::Trade(TradeError::InvalidPartner { .. }))
+    ));
+    assert!(matches!(
+        game.apply(player1, propose(None, wood, ResourceMap::empty())),
+        Err(ActionError::Trade(TradeError::OneSided))
+    ));
+    assert!(matches!(
+        game.apply(player1, propose(None, ResourceMap::new(99, 0, 0, 0, 0), ore)),
+        Err(ActionError::Trade(TradeError::InsufficientResources { player, .. })) if player == player1
+    ));
+
+    // An offer to everyone is closed once everyone has rejected it.
+    let events = game.apply(player1, propose(None, wood, ore)).unwrap();
+    let [GameEvent::TradeProposed { offer, .. }] = events[..] else {
+        panic!("Unexpected events {events:?}");
+    };
+    assert!(matches!(
+        game.apply(player1, Action::AcceptTrade(offer)),
+        Err(ActionError::Trade(TradeError::NoOffer { .. }))
+    ));
+    assert_eq!(
+        game.apply(player2, Action::RejectTrade(offer)).unwrap(),
+        vec![
+            GameEvent::TradeRejected {
+                offer,
+                player: player2
+            },
+            GameEvent::TradeClosed { offer }
+        ]
+    );
+    assert!(game.trade_offers().is_empty());
+
+    // A counter offer goes back to the proposer, who can accept it.
+    game.apply(player1, propose(Some(player2), wood, ore))
+        .unwrap();
+    let offer = game.trade_offers()[0].id();
+    let events = game
+        .apply(
+            player2,
+            Action::CounterTrade {
+                offer,
+                give: ore,
+                receive: wood + wood,
+            },
+        )
+        .unwrap();
+    let Some(&GameEvent::TradeProposed { offer: counter, .. }) = events.first() else {
+        panic!("Unexpected events {events:?}");
+    };
+    assert!(events.contains(&GameEvent::TradeClosed { offer }));
+    assert_eq!(game.trade_offers().len(), 1);
+    assert_eq!(game.trade_offers()[0].to(), Some(player1));
+
+    let held1 = game.find_player(player1).get_resources();
+    let held2 = game.find_player(player2).get_resources();
+    game.apply(player1, Action::AcceptTrade(counter)).unwrap();
+    assert_eq!(
+        game.find_player(player1).get_resources(),
+        held1 - wood - wood + ore
+    );
+    assert_eq!(
+        game.find_player(player2).get_resources(),
+        held2 - ore + wood + wood
+    );
+
+    // Both hands are checked when the trade executes, and nothing changes if either falls short.
+    game.apply(player1, propose(Some(player2), wood, ore))
+        .unwrap();
+    let offer = game.trade_offers()[0].id();
+    let held2 = game.find_player(player2).get_resources();
+    game.find_player_mut(player2).sub_resources(held2);
+    assert!(matches!(
+        game.apply(player2, Action::AcceptTrade(offer)),
+        Err(ActionError::Trade(TradeError::InsufficientResources { player, .. })) if player == player2
+    ));
+    assert_eq!(
+        game.find_player(player2).get_resources(),
+        ResourceMap::empty()
+    );
+    game.find_player_mut(player2).add_resources(held2);
+
+    assert_eq!(
+        game.apply(player1, Action::WithdrawTrade(offer)).unwrap(),
+        vec![GameEvent::TradeClosed { offer }]
+    );
+    assert!(matches!(
+        game.apply(player2, Action::RejectTrade(offer)),
+        Err(ActionError::Trade(TradeError::NoOffer { .. }))
+    ));
+
+    // Building ends the trading phase, which drops any open offers.
+    game.apply(player1, propose(None, wood, ore)).unwrap();
+    let offer = game.trade_offers()[0].id();
+    let events = game
+        .apply(
+            player1,
+            Action::BuildRoad((HexPosition::ORIGIN + EdgeOrientation::LEFT).into()),
+        )
+        .unwrap();
+    assert!(events.contains(&GameEvent::TradeClosed { offer }));
+    assert!(game.trade_offers().is_empty());
+    assert!(matches!(
+        game.apply(player1, propose(None, wood, ore)),
+        Err(ActionError::Trade(TradeError::Turn(
+            TurnError::WrongPhase { .. }
+        )))
+    ));
+}
+
+#[test]
+fn bank_trade_test() {
+    let mut rng = GameRng::seed_from_u64(3);
+    let mut game = Game::new(
+        edition::BaseEdition,
+        NonZeroUsize::new(2).unwrap(),
+        &mut rng,
+    );
+
+    let player1 = game.get_player(0).unwrap().token();
+    let player2 = game.get_player(1).unwrap().token();
+
+    for player in [player1, player2] {
+        game.find_player_mut(player)
+            .add_resources(ResourceMap::new(10, 10, 10, 10, 10));
+    }
+
+    place_setup(&mut game);
+
+    // The first player starts with a settlement on the wheat port, the second on the ore port.
+    assert_eq!(
+        RESOURCES.map(|r| game.trade_rate(player1, r)),
+        [4, 4, 2, 4, 4]
+    );
+    assert_eq!(
+        RESOURCES.map(|r| game.trade_rate(player2, r)),
+        [4, 4, 4, 4, 2]
+    );
+
+    let ports: Vec<_> = game.get_trade_ports().collect();
+    let any_port = ports
+        .iter()
+        .find(|p| p.get_type() == TradeType::Any)
+        .unwrap();
+    let sheep_port = ports
+        .iter()
+        .find(|p| p.get_type() == TradeType::Resource(ResourceType::Sheep))
+        .unwrap();
+
+    let settlement = Building::new(BuildingType::Settlement, player2);
+    game.board
+        .set_building(settlement, any_port.get_positions().0.into())
+        .unwrap();
+    assert_eq!(
+        RESOURCES.map(|r| game.trade_rate(player2, r)),
+        [3, 3, 3, 3, 2]
+    );
+    game.board
+        .set_building(settlement, sheep_port.get_positions().1.into())
+        .unwrap();
+    assert_eq!(
+        RESOURCES.map(|r| game.trade_rate(player2, r)),
+        [3, 3, 3, 2, 2]
+    );
+
+    let bank_trade = |give, receive| Action::TradeWithBank { give, receive };
+
+    assert!(matches!(
+        game.apply(
+            player1,
+            bank_trade(
+                ResourceMap::new(4, 0, 0, 0, 0),
+                ResourceMap::new(0, 1, 0, 0, 0)
+            )
+        ),
+        Err(ActionError::Trade(TradeError::Turn(
+            TurnError::WrongPhase { .. }
+        )))
+    ));
+
+    game.apply(player1, Action::RollDice).unwrap();
+    while let Some((player, action)) = robber_action(&game) {
+        game.apply(player, action).unwrap();
+    }
+
+    assert!(matches!(
+        game.apply(
+            player1,
+            bank_trade(
+                ResourceMap::new(0, 0, 3, 0, 0),
+                ResourceMap::new(0, 1, 0, 0, 0)
+            )
+        ),
+        Err(ActionError::Trade(TradeError::InvalidRate {
+            resource: ResourceType::Wheat,
+            rate: 2
+        }))
+    ));
+    assert!(matches!(
+        game.apply(
+            player1,
+            bank_trade(
+                ResourceMap::new(4, 0, 0, 0, 0),
+                ResourceMap::new(1, 0, 0, 0, 0)
+            )
+        ),
+        Err(ActionError::Trade(TradeError::SameResource(
+            ResourceType::Wood
+        )))
+    ));
+    assert!(matches!(
+        game.apply(
+            player1,
+            bank_trade(
+                ResourceMap::new(4, 0, 0, 0, 0),
+                ResourceMap::new(0, 1, 1, 0, 0)
+            )
+        ),
+        Err(ActionError::Trade(TradeError::UnbalancedBankTrade {
+            paid_for: 1,
+            requested: 2
+        }))
+    ));
+    assert!(matches!(
+        game.apply(
+            player1,
+            bank_trade(
+                ResourceMap::new(40, 0, 0, 0, 0),
+                ResourceMap::new(0, 10, 0, 0, 0)
+            )
+        ),
+        Err(ActionError::Trade(TradeError::InsufficientResources { .. }))
+    ));
+
+    let held = game.find_player(player1).get_resources();
+    let give = ResourceMap::new(4, 0, 2, 0, 0);
+    let receive = ResourceMap::new(0, 1, 0, 0, 1);
+    assert_eq!(
+        game.apply(player1, bank_trade(give, receive)).unwrap(),
+        vec![GameEvent::BankTradeCompleted {
+            player: player1,
+            give,
+            receive
+        }]
+    );
+    assert_eq!(
+        game.find_player(player1).get_resources(),
+        held - give + receive
+    );
+}
+
+#[test]
+fn bank_test() {
+    let mut rng = GameRng::seed_from_u64(3);
+    let mut game = Game::new(
+        edition::BaseEdition,
+        NonZeroUsize::new(3).unwrap(),
+        &mut rng,
+    );
+
+    let player1 = game.get_player(0).unwrap().token();
+    let player2 = game.get_player(1).unwrap().token();
+    let player3 = game.get_player(2).unwrap().token();
+
+    assert_eq!(
+        game.bank().resources(),
+        ResourceMap::new(19, 19, 19, 19, 19)
+    );
+
+    // Wood runs short for two players, so neither gets any. Brick only runs short for one, who gets what is left.
+    game.bank = Bank::new(ResourceMap::new(2, 2, 19, 19, 19));
+    game.produce_resources(vec![
+        (player1, ResourceMap::new(2, 0, 1, 0, 0)),
+        (player2, ResourceMap::new(1, 3, 0, 0, 0)),
+        (player3, ResourceMap::empty()),
+    ]);
+
+    assert_eq!(
+        game.events,
+        vec![
+            GameEvent::BankShortage {
+                resource: ResourceType::Wood
+            },
+            GameEvent::BankShortage {
+                resource: ResourceType::Brick
+            },
+            GameEvent::ResourcesProduced {
+                player: player1,
+                resources: ResourceMap::new(0, 0, 1, 0, 0)
+            },
+            GameEvent::ResourcesProduced {
+                player: player2,
+                resources: ResourceMap::new(0, 2, 0, 0, 0)
+            },
+        ]
+    );
+    assert_eq!(game.bank().resources(), ResourceMap::new(2, 0, 18, 19, 19));
+    assert_eq!(
+        game.find_player(player2).get_resources(),
+        ResourceMap::new(0, 2, 0, 0, 0)
+    );
+
+    // Demand the bank can meet exactly is handed out in full.
+    game.events.clear();
+    game.produce_resources(vec![
+        (player1, ResourceMap::new(1, 0, 0, 0, 0)),
+        (player3, ResourceMap::new(1, 0, 0, 0, 0)),
+    ]);
+    assert_eq!(game.bank().resources(), ResourceMap::new(0, 0, 18, 19, 19));
+    assert!(
+        !game
+            .events
+            .iter()
+            .any(|e| matches!(e, GameEvent::BankShortage { .. }))
+    );
+
+    // Cards traded to the bank go back into its supply, and it cannot give out what it does not have.
+    game.bank = Bank::new(ResourceMap::new(19, 0, 19, 19, 19));
+    for player in [player1, player2, player3] {
+        game.find_player_mut(player)
+            .add_resources(ResourceMap::new(10, 10, 10, 10, 10));
+    }
+    game.phase = TurnPhase::Trade;
+
+    assert!(matches!(
+        game.apply(
+            player1,
+            Action::TradeWithBank {
+                give: ResourceMap::new(4, 0, 0, 0, 0),
+                receive: ResourceMap::new(0, 1, 0, 0, 0)
+            }
+        ),
+        Err(ActionError::Trade(TradeError::BankShortage { .. }))
+    ));
+
+    let held = game.find_player(player1).get_resources();
+    game.apply(
+        player1,
+        Action::TradeWithBank {
+            give: ResourceMap::new(0, 4, 0, 0, 0),
+            receive: ResourceMap::new(0, 0, 0, 0, 1),
+        },
+    )
+    .unwrap();
+    assert_eq!(game.bank().resources(), ResourceMap::new(19, 4, 19, 19, 18));
+    assert_eq!(
+        game.find_player(player1).get_resources(),
+        held - ResourceMap::new(0, 4, 0, 0, 0) + ResourceMap::new(0, 0, 0, 0, 1)
+    );
+
+    game.apply(player1, Action::BuyDevelopmentCard).unwrap();
+    assert_eq!(game.bank().resources(), ResourceMap::new(19, 4, 20, 20, 19));
+}
+
+#[test]
+fn build_cost_test() {
+    let mut rng = GameRng::seed_from_u64(3);
+    let mut game = Game::new(
+        edition::BaseEdition,
+        NonZeroUsize::new(2).unwrap(),
+        &mut rng,
+    );
+
+    let player1 = game.get_player(0).unwrap().token();
+    let player2 = game.get_player(1).unwrap().token();
+
+    // Setup placement is free, so the only cards held are the starting resources.
+    place_setup(&mut game);
+    assert_eq!(
+        game.bank().resources()
+            + game.find_player(player1).get_resources()
+            + game.find_player(player2).get_resources(),
+        ResourceMap::new(19, 19, 19, 19, 19)
+    );
+
+    game.apply(player1, Action::RollDice).unwrap();
+    while let Some((player, action)) = robber_action(&game) {
+        game.apply(player, action).unwrap();
+    }
+
+    let held = game.find_player(player1).get_resources();
+    game.find_player_mut(player1).sub_resources(held);
+    game.find_player_mut(player1)
+        .add_resources(ResourceMap::new(1, 1, 3, 0, 2));
+    let bank = game.bank().resources();
+
+    let road = (HexPosition::ORIGIN + EdgeOrientation::LEFT).into();
+    game.apply(player1, Action::BuildRoad(road)).unwrap();
+    assert_eq!(
+        game.find_player(player1).get_resources(),
+        ResourceMap::new(0, 0, 3, 0, 2)
+    );
+    assert_eq!(
+        game.bank().resources(),
+        bank + ResourceMap::new(1, 1, 0, 0, 0)
+    );
+    assert_eq!(
+        game.find_player(player1)
+            .count_structure(StructureType::Road),
+        12
+    );
+
+    // A failed build leaves the hand, the supply of structures and the board untouched.
+    let corner = (HexPosition::ORIGIN + CornerHeight::TOP_LEFT).into();
+    let edge = game
+        .board
+        .neighboring_corners_for_edge(road)
+        .flat_map(|c| game.board.neighboring_edges_for_corner(c))
+        .find(|e| {
+            game.can_play_transport(Transport::new(TransportType::Road, player1), *e)
+                .is_ok()
+        })
+        .unwrap();
+    assert!(matches!(
+        game.apply(player1, Action::BuildRoad(edge)),
+        Err(ActionError::Build(BuildError::InsufficientResources {
+            structure: StructureType::Road,
+            ..
+        }))
+    ));
+    assert_eq!(
+        game.find_player(player1).get_resources(),
+        ResourceMap::new(0, 0, 3, 0, 2)
+    );
+    assert_eq!(
+        game.find_player(player1)
+            .count_structure(StructureType::Road),
+        12
+    );
+    assert_eq!(game.find_transport(edge), None);
+
+    // Upgrading to a city pays for it, returns the settlement, and replaces the settlement's production.
+    game.apply(player1, Action::BuildCity(corner)).unwrap();
+    assert_eq!(
+        game.find_player(player1).get_resources(),
+        ResourceMap::empty()
+    );
+    assert_eq!(
+        game.bank().resources(),
+        bank + ResourceMap::new(1, 1, 3, 0, 2)
+    );
+    assert_eq!(
+        game.find_player(player1)
+            .count_structure(StructureType::Settlement),
+        4
+    );
+    assert_eq!(
+        game.find_player(player1)
+            .count_structure(StructureType::City),
+        3
+    );
+    assert_eq!(game.buildings.len(), 4);
+    assert_eq!(
+        game.buildings
+            .iter()
+            .filter(|(_, p, _)| *p == corner)
+            .map(|(b, _, _)| b.r#type())
+            .collect::<Vec<_>>(),
+        vec![BuildingType::City]
+    );
+}
+
+#[test]
+fn starting_resources_test() {
+    let settlements = |game: &Game, player: OwnershipToken| -> Vec<ResourceMap> {
+        game.buildings
+            .iter()
+            .filter(|(b, _, _)| b.owner() == player)
+            .map(|(_, position, _)| {
+                game.board
+                    .neighboring_hex_for_corner(*position)
+                    .filter_map(|p| game.board.get_resource_type(p))
+                    .map(|r| (r, 1))
+                    .collect()
+            })
+            .collect()
+    };
+
+    for starting_resources in [
+        StartingResources::None,
+        StartingResources::FirstSettlement,
+        StartingResources::SecondSettlement,
+        StartingResources::BothSettlements,
+    ] {
+        let mut rng = GameRng::seed_from_u64(3);
+        let edition = edition::CustomEdition::of_size(3, 5, &mut rng)
+            .with_starting_resources(starting_resources)
+            .build();
+        let mut game = Game::new(edition, NonZeroUsize::new(2).unwrap(), &mut rng);
+
+        place_setup(&mut game);
+
+        for player in [game.current_turn(), game.get_player(1).unwrap().token()] {
+            let [first, second] = settlements(&game, player)[..] else {
+                panic!("Expected two settlements");
+            };
+            let expected = match starting_resources {
+                StartingResources::None => ResourceMap::empty(),
+                StartingResources::FirstSettlement => first,
+                StartingResources::SecondSettlement => second,
+                StartingResources::BothSettlements => first + second,
+            };
+            assert_eq!(game.find_player(player).get_resources(), expected);
+        }
+    }
+}
+
+#[test]
+fn legal_actions_test() {
+    let mut rng = GameRng::seed_from_u64(5);
+    let mut game = Game::new(
+        edition::BaseEdition,
+        NonZeroUsize::new(3).unwrap(),
+        &mut rng,
+    );
+
+    let players: Vec<OwnershipToken> = (0..3)
+        .map(|i| game.get_player(i).unwrap().token())
+        .collect();
+    for player in &players {
+        game.find_player_mut(*player)
+            .add_resources(ResourceMap::new(5, 5, 5, 5, 5));
+    }
+
+    let mut applied = Vec::new();
+
+    for _ in 0..200 {
+        let mut choices = Vec::new();
+
+        for player in &players {
+            let actions = game.legal_actions(*player);
+
+            // Every listed action must be accepted.
+            for action in &actions {
+                let mut copy = game.clone();
+                if let Err(error) = copy.apply(*player, *action) {
+                    panic!("{action:?} was listed for {player:?} but failed with {error}");
+                }
+            }
+
+            // And every building the player could place must be listed.
+            for corner in game.board.corner_positions() {
+                for (r#type, action) in [
+                    (BuildingType::Settlement, Action::BuildSettlement(corner)),
+                    (BuildingType::City, Action::BuildCity(corner)),
+                ] {
+                    if game
+                        .can_play_building(Building::new(r#type, *player), corner)
+                        .is_ok()
+                    {
+                        let mut copy = game.clone();
+                        assert_eq!(
+                            copy.apply(*player, action).is_ok(),
+                            actions.contains(&action),
+                            "{action:?} for {player:?}"
+                        );
+                    }
+                }
+            }
+
+            choices.extend(actions.into_iter().map(|a| (*player, a)));
+        }
+
+        let Some((player, action)) = choices.choose(&mut rng).copied() else {
+            break;
+        };
+        game.apply(player, action).unwrap();
+        applied.push(action);
+    }
+
+    // The walk got past setup, and built something after it.
+    assert!(!game.phase().is_setup());
+    assert!(applied.contains(&Action::RollDice));
+    assert!(
+        applied[16..]
+            .iter()
+            .any(|a| matches!(a, Action::BuildRoad(_) | Action::BuildSettlement(_)))
+    );
+}
+
+#[test]
+fn legal_actions_walk_test() {
+    // Whoever the game waits on must always have something to do, or a game played from the list gets stuck. These
+    // seeds used to end up in road building without anywhere left to build.
+    for (seed, players) in [(281, 4), (313, 4), (91, 6), (245, 6)] {
+        let mut rng = GameRng::seed_from_u64(seed);
+        let mut game = if players > 4 {
+            Game::new(
+                edition::ExpansionEdition,
+                NonZeroUsize::new(players).unwrap(),
+                &mut rng,
+            )
+        } else {
+            Game::new(
+                edition::BaseEdition,
+                NonZeroUsize::new(players).unwrap(),
+                &mut rng,
+            )
+        };
+
+        while let Some(player) = acting_player(&game) {
+            let actions = game.legal_actions(player);
+            assert!(
+                !actions.is_empty(),
+                "no legal action for {player:?} in {:?}",
+                game.phase()
+            );
+            game.apply(player, *actions.choose(&mut rng).unwrap())
+                .unwrap();
+        }
+
+        assert!(game.winner().is_some());
+    }
+}
+
+#[test]
+fn view_test() {
+    let mut rng = GameRng::seed_from_u64(2);
+    let mut game = Game::new(
+        edition::BaseEdition,
+        NonZeroUsize::new(3).unwrap(),
+        &mut rng,
+    );
+    game.phase = TurnPhase::Trade;
+
+    let players: Vec<OwnershipToken> = (0..3)
+        .map(|i| game.get_player(i).unwrap().token())
+        .collect();
+    for (i, player) in players.iter().enumerate() {
+        game.find_player_mut(*player)
+            .add_resources(ResourceMap::new(3 * i as u32, 1, 2, 0, 4));
+        game.find_player_mut(*player)
+            .add_resources(DevelopmentCard::cost());
+        game.buy_development_card(*player).unwrap();
+        game.phase = TurnPhase::Trade;
+        game.current_turn = players[(i + 1) % 3];
+    }
+
+    let view = game.view_for(players[0]);
+
+    // The viewer's hand is shown in full, everyone else's only as counts.
+    assert_eq!(
+        view.player().get_resources(),
+        game.find_player(players[0]).get_resources()
+    );
+    for player in &players[1..] {
+        let opponent = view.find_opponent(*player).unwrap();
+        assert_eq!(
+            opponent.resource_count(),
+            game.find_player(*player).get_resources().total()
+        );
+        assert_eq!(opponent.hidden_development_card_count(), 1);
+    }
+    assert_eq!(
+        view.unseen_resources(),
+        game.find_player(players[1]).get_resources() + game.find_player(players[2]).get_resources()
+    );
+    assert_eq!(
+        view.unseen_development_cards()
+            .iter()
+            .map(|(_, count)| *count as usize)
+            .sum::<usize>(),
+        game.development_cards_left() + 2
+    );
+    assert_eq!(view.legal_actions(), game.legal_actions(players[0]));
+
+    let hand = |game: &Game, player: OwnershipToken| {
+        let player = game.find_player(player);
+        let cards: Vec<DevelopmentCardType> =
+            player.development_cards().map(|c| c.get_type()).collect();
+        (player.get_resources(), cards)
+    };
+
+    let held = |game: &Game| {
+        players
+            .iter()
+            .map(|p| game.find_player(*p).get_resources())
+            .fold(ResourceMap::empty(), |a, b| a + b)
+    };
+
+    let mut changed = false;
+    for seed in 0..20 {
+        let copy = view.determinize(&mut GameRng::seed_from_u64(seed));
+
+        // The viewer's hand is untouched, while the others keep their hand sizes.
+        assert_eq!(hand(&copy, players[0]), hand(&game, players[0]));
+        for player in &players[1..] {
+            let (resources, cards) = hand(&copy, *player);
+            assert_eq!(
+                resources.total(),
+                game.find_player(*player).get_resources().total()
+            );
+            assert_eq!(cards.len(), 1);
+            changed |= hand(&copy, *player) != hand(&game, *player);
+        }
+
+        assert_eq!(copy.development_cards_left(), game.development_cards_left());
+        // Cards only moved between the other players.
+        assert_eq!(held(&copy), held(&game));
+        assert_eq!(
+            copy.legal_actions(players[0]),
+            game.legal_actions(players[0])
+        );
+    }
+    assert!(changed);
+}
+
+#[test]
+fn event_subscriber_test() {
+    struct TurnCounter(Rc<Cell<usize>>);
+
+    impl EventSubscriber for TurnCounter {
+        fn on_event(&mut self, event: &GameEvent) {
+            if let GameEvent::TurnEnded { .. } = event {
+                self.0.set(self.0.get() + 1);
+            }
+        }
+    }
+
+    let mut rng = GameRng::seed_from_u64(8);
+    let mut game = Game::new(
+        edition::BaseEdition,
+        NonZeroUsize::new(2).unwrap(),
+        &mut rng,
+    );
+
+    let queue = EventQueue::new();
+    let turns = Rc::new(Cell::new(0));
+    game.subscribe(queue.clone());
+    game.subscribe(TurnCounter(turns.clone()));
+
+    let players: Vec<OwnershipToken> = (0..2)
+        .map(|i| game.get_player(i).unwrap().token())
+        .collect();
+
+    // Rejected actions change nothing, so nobody hears about them.
+    assert!(game.apply(players[1], Action::EndTurn).is_err());
+    assert!(queue.is_empty());
+
+    let mut applied = Vec::new();
+    for _ in 0..100 {
+        let Some((player, action)) = players
+            .iter()
+            .flat_map(|p| game.legal_actions(*p).into_iter().map(|a| (*p, a)))
+            .collect::<Vec<_>>()
+            .choose(&mut rng)
+            .copied()
+        else {
+            break;
+        };
+        applied.extend(game.apply(player, action).unwrap());
+    }
+
+    // Subscribers see the same events apply returns, in the same order.
+    assert!(!applied.is_empty());
+    assert_eq!(queue.drain(), applied);
+    assert!(queue.is_empty());
+    assert_eq!(
+        turns.get(),
+        applied
+            .iter()
+            .filter(|e| matches!(e, GameEvent::TurnEnded { .. }))
+            .count()
+    );
+
+    // Copies and restored saves start out without subscribers.
+    let mut copy = game.clone();
+    let mut restored = Game::from_bytes(&game.to_bytes().unwrap()).unwrap();
+    for game in [&mut copy, &mut restored] {
+        let player = game.current_turn();
+        let action = game.legal_actions(player)[0];
+        game.apply(player, action).unwrap();
+    }
+    assert!(queue.is_empty());
+}
+
+#[test]
+fn event_redaction_test() {
+    let mut rng = GameRng::seed_from_u64(3);
+    let mut game = Game::new(
+        edition::BaseEdition,
+        NonZeroUsize::new(3).unwrap(),
+        &mut rng,
+    );
+
+    let players: Vec<OwnershipToken> = (0..3)
+        .map(|i| game.get_player(i).unwrap().token())
+        .collect();
+    let queues: Vec<EventQueue> = players.iter().map(|_| EventQueue::new()).collect();
+    for queue in &queues {
+        game.subscribe(queue.clone());
+    }
+
+    let mut applied = Vec::new();
+    while !applied
+        .iter()
+        .any(|e| matches!(e, GameEvent::CardStolen { .. }))
+        || !applied
+            .iter()
+            .any(|e| matches!(e, GameEvent::CardsDiscarded { .. }))
+    {
+        let player = acting_player(&game).unwrap();
+        let action = *game.legal_actions(player).choose(&mut rng).unwrap();
+        applied.extend(game.apply(player, action).unwrap());
+    }
+
+    for (viewer, queue) in players.iter().zip(&queues) {
+        let seen = queue.drain_for(*viewer);
+        assert_eq!(seen.len(), applied.len());
+
+        for (seen, event) in seen.iter().zip(&applied) {
+            match *event {
+                GameEvent::CardStolen { thief, victim, .. }
+                    if *viewer != thief && *viewer != victim =>
+                {
+                    assert_eq!(*seen, GameEvent::HiddenCardStolen { thief, victim })
+                }
+                GameEvent::CardsDiscarded { player, resources } if *viewer != player => assert_eq!(
+                    *seen,
+                    GameEvent::HiddenCardsDiscarded {
+                        player,
+                        count: resources.total()
+                    }
+                ),
+                _ => assert_eq!(seen, event),
+            }
+        }
+    }
+}
+
+fn is_trail(game: &Game, owner: OwnershipToken, trail: &[(Transport, EdgePosition)]) -> bool {
+    let mut previous = None;
+
+    for pair in trail.windows(2) {
+        let [(first, first_position), (second, second_position)] = pair else {
+            unreachable!()
+        };
+
+        let Some(shared) = first_position
+            .neighboring_corners()
+            .into_iter()
+            .find(|c| second_position.neighboring_corners().contains(c))
+        else {
+            return false;
+        };
+
+        if previous == Some(shared) {
+            return false;
+        }
+
+        match game.find_building(shared) {
+            Some(b) if b.owner() != owner => return false,
+            None if first.r#type() != second.r#type() => return false,
+            _ => {}
+        }
+
+        previous = Some(shared);
+    }
+
+    true
+}
+
+/// Tries every ordering of every subset of the network.
+fn brute_force_longest_road(
+    game: &Game,
+    owner: OwnershipToken,
+    network: &[(Transport, EdgePosition)],
+    trail: &mut Vec<(Transport, EdgePosition)>,
+) -> u32 {
+    let mut longest = if is_trail(game, owner, trail) {
+        trail.len() as u32
+    } else {
+        0
+    };
+
+    for transport in network {
+        if trail.contains(transport) {
+            continue;
+        }
+
+        trail.push(*transport);
+        longest = longest.max(brute_force_longest_road(game, owner, network, trail));
+        trail.pop();
+    }
+
+    longest
+}
+
+#[test]
+fn longest_road_oracle_test() {
+    let mut rng = GameRng::seed_from_u64(2);
+
+    let mut pool: Vec<EdgePosition> = Vec::new();
+    for hex in [
+        HexPosition::ORIGIN,
+        HexPosition::RIGHT,
+        HexPosition::DOWN_LEFT,
+        HexPosition::DOWN_RIGHT,
+    ] {
+        for edge in [
+            (hex + EdgeOrientation::TOP_LEFT).into(),
+            (hex + EdgeOrientation::BOTTOM_RIGHT).into(),
+            (hex + EdgeOrientation::TOP_RIGHT).into(),
+            (hex + EdgeOrientation::BOTTOM_LEFT).into(),
+            (hex + EdgeOrientation::LEFT).into(),
+            (hex + EdgeOrientation::RIGHT).into(),
+        ] {
+            if !pool.contains(&edge) {
+                pool.push(edge);
+            }
+        }
+    }
+
+    for _ in 0..100 {
+        let edition = edition::CustomEdition::of_size(3, 5, &mut rng).build();
+        let mut game = Game::new(edition, NonZeroUsize::new(2).unwrap(), &mut rng);
+
+        let owner = game.get_player(0).unwrap().token();
+        let opponent = game.get_player(1).unwrap().token();
+
+        // grow a connected network from a random starting edge
+        let count = rng.random_range(3..=7);
+        let mut positions = vec![*pool.choose(&mut rng).unwrap()];
+        while positions.len() < count {
+            let candidates: Vec<EdgePosition> = pool
+                .iter()
+                .copied()
+                .filter(|p| !positions.contains(p))
+                .filter(|p| {
+                    positions.iter().any(|q| {
+                        p.neighboring_corners()
+                            .iter()
+                            .any(|c| q.neighboring_corners().contains(c))
+                    })
+                })
+                .collect();
+            positions.push(*candidates.choose(&mut rng).unwrap());
+        }
+
+        let mut network = Vec::new();
+        for position in positions.iter() {
+            let r#type = if rng.random_bool(0.8) {
+                TransportType::Road
+            } else {
+                TransportType::Boat
+            };
+            let transport = Transport::new(r#type, owner);
+
+            game.board.set_transport(transport, *position).unwrap();
+            game.transports.push((transport, *position));
+            network.push((transport, *position));
+
+            for corner in position.neighboring_corners() {
+                if game.find_building(corner).is_some() {
+                    continue;
+                }
+
+                let building = match rng.random_range(0..6) {
+                    0 => Building::new(BuildingType::Settlement, owner),
+                    1 => Building::new(BuildingType::Settlement, opponent),
+                    _ => continue,
+                };
+                game.board.set_building(building, corner).unwrap();
+            }
+        }
+
+        assert_eq!(
+            game.calculate_longest_road(owner),
+            brute_force_longest_road(&game, owner, &network, &mut Vec::new()),
+            "{network:?}"
+        );
+    }
 }
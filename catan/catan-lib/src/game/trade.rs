@@ -0,0 +1,373 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    game::{
+        Game,
+        error::{TradeError, TurnError},
+        event::GameEvent,
+        phase::TurnPhase,
+        player::OwnershipToken,
+    },
+    object::{
+        card::ResourceMap,
+        resource::{ResourceType, Resources},
+        trade::TradeType,
+    },
+};
+
+/// The number of cards the bank asks for one card of any resource, without a port.
+const BANK_RATE: u32 = 4;
+/// The rate of a port which accepts any resource.
+const ANY_PORT_RATE: u32 = 3;
+/// The rate of a port which accepts one specific resource.
+const RESOURCE_PORT_RATE: u32 = 2;
+
+/// Identifies a trade offer for as long as it is open.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TradeOfferId(u32);
+
+/// A proposal to exchange resources between two players. Either the current player makes an offer to one or all of
+/// the other players, or another player counters one of their offers.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TradeOffer {
+    id: TradeOfferId,
+    from: OwnershipToken,
+    /// `None` if the offer is open to every other player.
+    to: Option<OwnershipToken>,
+    give: ResourceMap,
+    receive: ResourceMap,
+    rejected_by: Vec<OwnershipToken>,
+}
+
+impl TradeOffer {
+    pub fn id(&self) -> TradeOfferId {
+        self.id
+    }
+
+    pub fn from(&self) -> OwnershipToken {
+        self.from
+    }
+
+    pub fn to(&self) -> Option<OwnershipToken> {
+        self.to
+    }
+
+    /// The resources the proposing player hands over.
+    pub fn give(&self) -> ResourceMap {
+        self.give
+    }
+
+    /// The resources the proposing player asks for in return.
+    pub fn receive(&self) -> ResourceMap {
+        self.receive
+    }
+
+    /// Returns true if the given player may still accept, reject or counter this offer.
+    pub fn is_addressed_to(&self, token: OwnershipToken) -> bool {
+        token != self.from
+            && self.to.is_none_or(|t| t == token)
+            && !self.rejected_by.contains(&token)
+    }
+}
+
+impl Game {
+    pub fn trade_offers(&self) -> &[TradeOffer] {
+        &self.trade_offers
+    }
+
+    pub fn find_trade_offer(&self, id: TradeOfferId) -> Option<&TradeOffer> {
+        self.trade_offers.iter().find(|o| o.id == id)
+    }
+
+    /// Offers resources from the current player's hand to another player, or to everyone if `to` is `None`.
+    pub fn propose_trade(
+        &mut self,
+        token: OwnershipToken,
+        to: Option<OwnershipToken>,
+        give: ResourceMap,
+        receive: ResourceMap,
+    ) -> Result<TradeOfferId, TradeError> {
+        self.check_trade_phase(token)?;
+
+        if let Some(to) = to {
+            self.check_trade_partner(token, to)?;
+        }
+
+        self.open_trade_offer(token, to, give, receive)
+    }
+
+    /// Accepts an open offer addressed to the given player, exchanging the resources of both sides at once.
+    pub fn accept_trade(
+        &mut self,
+        token: OwnershipToken,
+        id: TradeOfferId,
+    ) -> Result<(), TradeError> {
+        let offer = self.addressed_trade_offer(token, id)?.clone();
+
+        let held = self.find_player(offer.from).get_resources();
+        if !held.contains(&offer.give) {
+            return Err(TradeError::InsufficientResources {
+                player: offer.from,
+                held,
+            });
+        }
+
+        let held = self.find_player(token).get_resources();
+        if !held.contains(&offer.receive) {
+            return Err(TradeError::InsufficientResources {
+                player: token,
+                held,
+            });
+        }
+
+        let proposer = self.find_player_mut(offer.from);
+        proposer.sub_resources(offer.give);
+        proposer.add_resources(offer.receive);
+
+        let partner = self.find_player_mut(token);
+        partner.sub_resources(offer.receive);
+        partner.add_resources(offer.give);
+
+        self.trade_offers.retain(|o| o.id != id);
+
+        self.emit(GameEvent::TradeCompleted {
+            offer: id,
+            from: offer.from,
+            to: token,
+            give: offer.give,
+            receive: offer.receive,
+        });
+
+        Ok(())
+    }
+
+    /// Turns down an open offer. Once everyone it was addressed to has rejected it, the offer is closed.
+    pub fn reject_trade(
+        &mut self,
+        token: OwnershipToken,
+        id: TradeOfferId,
+    ) -> Result<(), TradeError> {
+        self.addressed_trade_offer(token, id)?;
+
+        self.emit(GameEvent::TradeRejected {
+            offer: id,
+            player: token,
+        });
+
+        let others: Vec<OwnershipToken> = self.players.iter().map(|p| p.token()).collect();
+        let offer = self
+            .trade_offers
+            .iter_mut()
+            .find(|o| o.id == id)
+            .expect("Offer was just found!");
+        offer.rejected_by.push(token);
+
+        if !others.iter().any(|t| offer.is_addressed_to(*t)) {
+            self.trade_offers.retain(|o| o.id != id);
+            self.emit(GameEvent::TradeClosed { offer: id });
+        }
+
+        Ok(())
+    }
+
+    /// Rejects an open offer and proposes different terms back to the player who made it. `give` and `receive` are from
+    /// the point of view of the countering player.
+    pub fn counter_trade(
+        &mut self,
+        token: OwnershipToken,
+        id: TradeOfferId,
+        give: ResourceMap,
+        receive: ResourceMap,
+    ) -> Result<TradeOfferId, TradeError> {
+        let from = self.addressed_trade_offer(token, id)?.from;
+
+        let counter = self.open_trade_offer(token, Some(from), give, receive)?;
+        self.reject_trade(token, id)?;
+
+        Ok(counter)
+    }
+
+    /// Takes back an offer the given player made.
+    pub fn withdraw_trade(
+        &mut self,
+        token: OwnershipToken,
+        id: TradeOfferId,
+    ) -> Result<(), TradeError> {
+        self.check_trade_phase(self.current_turn)?;
+
+        match self.find_trade_offer(id) {
+            Some(offer) if offer.from == token => {}
+            _ => return Err(TradeError::NoOffer { id, player: token }),
+        }
+
+        self.trade_offers.retain(|o| o.id != id);
+        self.emit(GameEvent::TradeClosed { offer: id });
+
+        Ok(())
+    }
+
+    /// The number of cards of the given resource the player has to hand over to the bank for a single card, using the
+    /// best port they have a building on.
+    pub fn trade_rate(&self, token: OwnershipToken, resource: ResourceType) -> u32 {
+        self.board
+            .trade_ports()
+            .filter(|port| {
+                let (low, high) = port.get_positions();
+                [low.into(), high.into()].into_iter().any(|corner| {
+                    self.find_building(corner)
+                        .is_some_and(|b| b.owner() == token)
+                })
+            })
+            .map(|port| match port.get_type() {
+                TradeType::Resource(r) if r == resource => RESOURCE_PORT_RATE,
+                TradeType::Resource(_) => BANK_RATE,
+                TradeType::Any => ANY_PORT_RATE,
+            })
+            .fold(BANK_RATE, u32::min)
+    }
+
+    /// Exchanges resources with the bank at the player's trade rates. Every resource given must be a multiple of its
+    /// rate, and each multiple pays for one of the cards received.
+    pub fn trade_with_bank(
+        &mut self,
+        token: OwnershipToken,
+        give: ResourceMap,
+        receive: ResourceMap,
+    ) -> Result<(), TradeError> {
+        self.check_trade_phase(token)?;
+
+        if give.total() == 0 || receive.total() == 0 {
+            return Err(TradeError::OneSided);
+        }
+
+        let mut paid_for = 0;
+        for resource in Resources::new() {
+            let count = give.get(resource).get_count();
+            if count == 0 {
+                continue;
+            }
+
+            if receive.get(resource).get_count() > 0 {
+                return Err(TradeError::SameResource(resource));
+            }
+
+            let rate = self.trade_rate(token, resource);
+            if !count.is_multiple_of(rate) {
+                return Err(TradeError::InvalidRate { resource, rate });
+            }
+            paid_for += count / rate;
+        }
+
+        if paid_for != receive.total() {
+            return Err(TradeError::UnbalancedBankTrade {
+                paid_for,
+                requested: receive.total(),
+            });
+        }
+
+        let held = self.find_player(token).get_resources();
+        if !held.contains(&give) {
+            return Err(TradeError::InsufficientResources {
+                player: token,
+                held,
+            });
+        }
+
+        if !self.bank.can_supply(&receive) {
+            return Err(TradeError::BankShortage {
+                available: self.bank.resources(),
+            });
+        }
+
+        self.return_to_bank(token, give);
+        self.take_from_bank(token, receive);
+
+        self.emit(GameEvent::BankTradeCompleted {
+            player: token,
+            give,
+            receive,
+        });
+
+        Ok(())
+    }
+
+    /// Drops every open offer, which happens as soon as the trading phase ends.
+    pub(super) fn close_trade_offers(&mut self) {
+        for offer in std::mem::take(&mut self.trade_offers) {
+            self.emit(GameEvent::TradeClosed { offer: offer.id });
+        }
+    }
+
+    fn check_trade_phase(&self, token: OwnershipToken) -> Result<(), TurnError> {
+        self.check_turn(token)?;
+
+        if self.phase != TurnPhase::Trade {
+            return Err(TurnError::WrongPhase { phase: self.phase });
+        }
+
+        Ok(())
+    }
+
+    fn check_trade_partner(
+        &self,
+        token: OwnershipToken,
+        to: OwnershipToken,
+    ) -> Result<(), TradeError> {
+        if to == token || !self.players.iter().any(|p| p.token() == to) {
+            return Err(TradeError::InvalidPartner { partner: to });
+        }
+
+        Ok(())
+    }
+
+    fn addressed_trade_offer(
+        &self,
+        token: OwnershipToken,
+        id: TradeOfferId,
+    ) -> Result<&TradeOffer, TradeError> {
+        self.check_trade_phase(self.current_turn)?;
+
+        self.find_trade_offer(id)
+            .filter(|o| o.is_addressed_to(token))
+            .ok_or(TradeError::NoOffer { id, player: token })
+    }
+
+    fn open_trade_offer(
+        &mut self,
+        from: OwnershipToken,
+        to: Option<OwnershipToken>,
+        give: ResourceMap,
+        receive: ResourceMap,
+    ) -> Result<TradeOfferId, TradeError> {
+        if give.total() == 0 || receive.total() == 0 {
+            return Err(TradeError::OneSided);
+        }
+
+        let held = self.find_player(from).get_resources();
+        if !held.contains(&give) {
+            return Err(TradeError::InsufficientResources { player: from, held });
+        }
+
+        let id = TradeOfferId(self.next_trade_offer);
+        self.next_trade_offer += 1;
+
+        self.trade_offers.push(TradeOffer {
+            id,
+            from,
+            to,
+            give,
+            receive,
+            rejected_by: Vec::new(),
+        });
+
+        self.emit(GameEvent::TradeProposed {
+            offer: id,
+            from,
+            to,
+            give,
+            receive,
+        });
+
+        Ok(id)
+    }
+}
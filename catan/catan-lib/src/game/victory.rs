@@ -0,0 +1,94 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    game::{Game, event::GameEvent, phase::TurnPhase, player::OwnershipToken},
+    object::structure::building::BuildingType,
+};
+
+/// The number of victory points awarded by each of the Longest Road and Largest Army cards.
+const SPECIAL_CARD_POINTS: u32 = 2;
+
+/// A breakdown of where a player's victory points come from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct VictoryPoints {
+    pub settlements: u32,
+    pub cities: u32,
+    pub longest_road: u32,
+    pub largest_army: u32,
+    /// Victory point cards that have been revealed.
+    pub revealed_cards: u32,
+    /// Victory point cards still in the player's hand, which only the player knows about.
+    pub hidden_cards: u32,
+}
+
+impl VictoryPoints {
+    /// The points every player can see.
+    pub fn public(&self) -> u32 {
+        self.settlements + self.cities + self.longest_road + self.largest_army + self.revealed_cards
+    }
+
+    pub fn total(&self) -> u32 {
+        self.public() + self.hidden_cards
+    }
+}
+
+impl Game {
+    pub fn victory_points(&self, token: OwnershipToken) -> VictoryPoints {
+        let mut points = VictoryPoints::default();
+
+        for building in self.board.buildings().filter(|b| b.owner() == token) {
+            match building.r#type() {
+                BuildingType::Settlement => points.settlements += 1,
+                BuildingType::City => points.cities += 2,
+            }
+        }
+
+        if self.longest_road == Some(token) {
+            points.longest_road = SPECIAL_CARD_POINTS;
+        }
+
+        if self.largest_army == Some(token) {
+            points.largest_army = SPECIAL_CARD_POINTS;
+        }
+
+        for card in self
+            .find_player(token)
+            .development_cards()
+            .filter(|c| c.is_victory_point())
+        {
+            if card.is_played() {
+                points.revealed_cards += 1;
+            } else {
+                points.hidden_cards += 1;
+            }
+        }
+
+        points
+    }
+
+    pub fn victory_point_target(&self) -> u32 {
+        self.victory_point_target
+    }
+
+    pub fn winner(&self) -> Option<OwnershipToken> {
+        match self.phase {
+            TurnPhase::GameOver { winner } => Some(winner),
+            _ => None,
+        }
+    }
+
+    /// Ends the game if the current player has reached the target. Players can only win on their own turn, so points
+    /// gained during someone else's turn are only counted once their own turn starts.
+    pub(super) fn check_victory(&mut self) {
+        if self.winner().is_some() || self.phase.is_setup() {
+            return;
+        }
+
+        let player = self.current_turn;
+
+        if self.victory_points(player).total() >= self.victory_point_target {
+            self.set_phase(TurnPhase::GameOver { winner: player });
+            self.emit(GameEvent::GameWon { player });
+        }
+    }
+}
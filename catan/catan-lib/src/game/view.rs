@@ -0,0 +1,355 @@
+use std::iter;
+
+use hexgrid::{
+    corner::position::CornerPosition, edge::position::EdgePosition, hex::position::HexPosition,
+};
+use rand::{SeedableRng, prelude::*};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    board::Board,
+    game::{
+        Game, GameRng,
+        action::Action,
+        bank::Bank,
+        edition::StartingResources,
+        event::EventSubscribers,
+        phase::TurnPhase,
+        player::{OwnershipToken, Player},
+        trade::{TradeOffer, TradeOfferId},
+        victory::VictoryPoints,
+    },
+    object::{
+        TileType,
+        card::{
+            DEVELOPMENT_CARDS, DevelopmentCard, DevelopmentCardDeck, DevelopmentCardType,
+            ResourceMap,
+        },
+        resource::{ResourceType, Resources},
+        structure::{OwnedStructures, StructureType, building::Building, transport::Transport},
+        trade::TradeType,
+    },
+};
+
+/// What another player's hand looks like from across the table.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OpponentView {
+    token: OwnershipToken,
+    structures: OwnedStructures,
+    resource_count: u32,
+    /// Development cards which have been played, and so are face up.
+    revealed_development_cards: Vec<DevelopmentCard>,
+    /// The turn each face down development card was bought on.
+    hidden_development_cards: Vec<usize>,
+    /// Without the victory point cards the player has not revealed.
+    victory_points: VictoryPoints,
+}
+
+impl OpponentView {
+    pub fn token(&self) -> OwnershipToken {
+        self.token
+    }
+
+    pub fn count_structure(&self, structure: StructureType) -> u32 {
+        self.structures.get_structure(structure)
+    }
+
+    pub fn resource_count(&self) -> u32 {
+        self.resource_count
+    }
+
+    pub fn revealed_development_cards(&self) -> &[DevelopmentCard] {
+        &self.revealed_development_cards
+    }
+
+    pub fn hidden_development_card_count(&self) -> usize {
+        self.hidden_development_cards.len()
+    }
+
+    pub fn victory_points(&self) -> VictoryPoints {
+        self.victory_points
+    }
+}
+
+/// A snapshot of the game as one player sees it. Their own hand is shown in full, while other hands are reduced to the
+/// number of cards in them, and the development card deck to its size. The dice to come are not part of it either.
+///
+/// Everything else is public, including which cards are unaccounted for: the resource cards in other hands follow from
+/// the bank and the player's own hand, and the face down development cards from the edition and the cards played.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerView {
+    viewer: OwnershipToken,
+    player: Player,
+    opponents: Vec<OpponentView>,
+    turn_order: Vec<OwnershipToken>,
+    board: Board,
+    current_turn: OwnershipToken,
+    turn_number: usize,
+    phase: TurnPhase,
+    discards: Vec<(OwnershipToken, u32)>,
+    resume_phase: TurnPhase,
+    bank: Bank,
+    development_cards_left: usize,
+    /// The resource cards held by all other players together.
+    unseen_resources: ResourceMap,
+    /// The development cards in the deck and in other players' hands together, as counts.
+    unseen_development_cards: Vec<(DevelopmentCardType, u32)>,
+    played_development_card: bool,
+    longest_road: Option<OwnershipToken>,
+    largest_army: Option<OwnershipToken>,
+    victory_point_target: u32,
+    starting_resources: StartingResources,
+    trade_offers: Vec<TradeOffer>,
+    next_trade_offer: u32,
+    buildings: Vec<(Building, CornerPosition, Vec<HexPosition>)>,
+    transports: Vec<(Transport, EdgePosition)>,
+    legal_actions: Vec<Action>,
+}
+
+impl Game {
+    /// Takes a snapshot of everything the given player is allowed to know. Anything shown to a player, whether through
+    /// the WASM interface or to a bot, should go through this.
+    pub fn view_for(&self, token: OwnershipToken) -> PlayerView {
+        let opponents: Vec<OpponentView> = self
+            .players
+            .iter()
+            .filter(|p| p.token() != token)
+            .map(|p| {
+                let (revealed, hidden): (Vec<&DevelopmentCard>, Vec<&DevelopmentCard>) =
+                    p.development_cards().partition(|c| c.is_played());
+
+                OpponentView {
+                    token: p.token(),
+                    structures: p.owned_structures(),
+                    resource_count: p.get_resources().total(),
+                    revealed_development_cards: revealed.into_iter().cloned().collect(),
+                    hidden_development_cards: hidden.iter().map(|c| c.bought_on()).collect(),
+                    victory_points: VictoryPoints {
+                        hidden_cards: 0,
+                        ..self.victory_points(p.token())
+                    },
+                }
+            })
+            .collect();
+
+        let unseen_resources = self
+            .players
+            .iter()
+            .filter(|p| p.token() != token)
+            .fold(ResourceMap::empty(), |unseen, p| unseen + p.get_resources());
+
+        let unseen_development_cards = DEVELOPMENT_CARDS
+            .map(|card| {
+                let in_hands = self
+                    .players
+                    .iter()
+                    .filter(|p| p.token() != token)
+                    .flat_map(|p| p.development_cards())
+                    .filter(|c| !c.is_played() && c.get_type() == card)
+                    .count();
+                let in_deck = self
+                    .development_cards
+                    .cards()
+                    .filter(|c| *c == card)
+                    .count();
+                (card, (in_hands + in_deck) as u32)
+            })
+            .to_vec();
+
+        PlayerView {
+            viewer: token,
+            player: self.find_player(token).clone(),
+            opponents,
+            turn_order: self.players.iter().map(|p| p.token()).collect(),
+            board: self.board.clone(),
+            current_turn: self.current_turn,
+            turn_number: self.turn_number,
+            phase: self.phase,
+            discards: self.discards.clone(),
+            resume_phase: self.resume_phase,
+            bank: self.bank.clone(),
+            development_cards_left: self.development_cards.len(),
+            unseen_resources,
+            unseen_development_cards,
+            played_development_card: self.played_development_card,
+            longest_road: self.longest_road,
+            largest_army: self.largest_army,
+            victory_point_target: self.victory_point_target,
+            starting_resources: self.starting_resources,
+            trade_offers: self.trade_offers.clone(),
+            next_trade_offer: self.next_trade_offer,
+            buildings: self.buildings.clone(),
+            transports: self.transports.clone(),
+            legal_actions: self.legal_actions(token),
+        }
+    }
+}
+
+impl PlayerView {
+    pub fn viewer(&self) -> OwnershipToken {
+        self.viewer
+    }
+
+    /// The viewing player, with their full hand.
+    pub fn player(&self) -> &Player {
+        &self.player
+    }
+
+    pub fn opponents(&self) -> &[OpponentView] {
+        &self.opponents
+    }
+
+    pub fn find_opponent(&self, token: OwnershipToken) -> Option<&OpponentView> {
+        self.opponents.iter().find(|o| o.token == token)
+    }
+
+    /// Every player's token, in turn order.
+    pub fn turn_order(&self) -> &[OwnershipToken] {
+        &self.turn_order
+    }
+
+    pub fn get_tile_type(&self, position: HexPosition) -> TileType {
+        self.board.get_tile(position).get_tile_type()
+    }
+
+    pub fn get_trade(&self, position: CornerPosition) -> Option<TradeType> {
+        self.board.get_trade(position)
+    }
+
+    pub fn get_robber(&self) -> HexPosition {
+        self.board.get_robber()
+    }
+
+    pub fn find_building(&self, position: CornerPosition) -> Option<Building> {
+        self.board.get_building(position)
+    }
+
+    pub fn find_transport(&self, position: EdgePosition) -> Option<Transport> {
+        self.board.get_transport(position)
+    }
+
+    /// Every building on the board, along with its position.
+    pub fn buildings(&self) -> impl Iterator<Item = (Building, CornerPosition)> {
+        self.buildings.iter().map(|(b, p, _)| (*b, *p))
+    }
+
+    pub fn current_turn(&self) -> OwnershipToken {
+        self.current_turn
+    }
+
+    pub fn turn_number(&self) -> usize {
+        self.turn_number
+    }
+
+    pub fn phase(&self) -> TurnPhase {
+        self.phase
+    }
+
+    pub fn pending_discard(&self, token: OwnershipToken) -> Option<u32> {
+        self.discards
+            .iter()
+            .find(|(t, _)| *t == token)
+            .map(|(_, count)| *count)
+    }
+
+    pub fn bank(&self) -> &Bank {
+        &self.bank
+    }
+
+    pub fn development_cards_left(&self) -> usize {
+        self.development_cards_left
+    }
+
+    pub fn unseen_resources(&self) -> ResourceMap {
+        self.unseen_resources
+    }
+
+    pub fn unseen_development_cards(&self) -> &[(DevelopmentCardType, u32)] {
+        &self.unseen_development_cards
+    }
+
+    pub fn trade_offers(&self) -> &[TradeOffer] {
+        &self.trade_offers
+    }
+
+    pub fn find_trade_offer(&self, id: TradeOfferId) -> Option<&TradeOffer> {
+        self.trade_offers.iter().find(|o| o.id() == id)
+    }
+
+    pub fn victory_point_target(&self) -> u32 {
+        self.victory_point_target
+    }
+
+    /// Every action the viewing player could successfully apply, see `Game::legal_actions`.
+    pub fn legal_actions(&self) -> &[Action] {
+        &self.legal_actions
+    }
+
+    /// Builds a complete game which matches this view, dealing the unseen cards at random and seeding new dice. Bots
+    /// use this to search ahead without knowing more than the player they play for.
+    pub fn determinize(&self, rng: &mut GameRng) -> Game {
+        let mut resources: Vec<ResourceType> = Vec::new();
+        for resource in Resources::new() {
+            let count = self.unseen_resources.get(resource).get_count();
+            resources.extend(iter::repeat_n(resource, count as usize));
+        }
+        resources.shuffle(rng);
+
+        let mut cards: Vec<DevelopmentCardType> = Vec::new();
+        for (card, count) in &self.unseen_development_cards {
+            cards.extend(iter::repeat_n(*card, *count as usize));
+        }
+        cards.shuffle(rng);
+
+        let mut resources = resources.into_iter();
+        let mut cards = cards.into_iter();
+
+        let players = self
+            .turn_order
+            .iter()
+            .map(|token| {
+                let Some(opponent) = self.find_opponent(*token) else {
+                    return self.player.clone();
+                };
+
+                let mut player = Player::restore(opponent.token, opponent.structures);
+                for resource in resources.by_ref().take(opponent.resource_count as usize) {
+                    player.add_resource(resource, 1);
+                }
+                for card in &opponent.revealed_development_cards {
+                    player.add_development_card(card.clone());
+                }
+                for bought_on in &opponent.hidden_development_cards {
+                    let card = cards.next().expect("Every face down card is unseen!");
+                    player.add_development_card(DevelopmentCard::new(card, *bought_on));
+                }
+
+                player
+            })
+            .collect();
+
+        Game {
+            board: self.board.clone(),
+            players,
+            current_turn: self.current_turn,
+            turn_number: self.turn_number,
+            phase: self.phase,
+            rng: GameRng::from_rng(rng),
+            events: Vec::new(),
+            subscribers: EventSubscribers::default(),
+            discards: self.discards.clone(),
+            resume_phase: self.resume_phase,
+            bank: self.bank.clone(),
+            development_cards: DevelopmentCardDeck::from_cards(cards.collect()),
+            played_development_card: self.played_development_card,
+            longest_road: self.longest_road,
+            largest_army: self.largest_army,
+            victory_point_target: self.victory_point_target,
+            starting_resources: self.starting_resources,
+            trade_offers: self.trade_offers.clone(),
+            next_trade_offer: self.next_trade_offer,
+            buildings: self.buildings.clone(),
+            transports: self.transports.clone(),
+        }
+    }
+}
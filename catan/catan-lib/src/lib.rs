@@ -1,4 +1,5 @@
 pub mod board;
+pub mod bot;
 pub mod distribution;
 pub mod game;
 pub mod object;
@@ -1,8 +1,21 @@
-use std::ops::Add;
+use std::{
+    iter,
+    ops::{Add, Sub},
+};
 
-use crate::object::resource::ResourceType;
+use rand::seq::SliceRandom;
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
 
-#[derive(Debug, Clone, PartialEq)]
+use crate::{
+    distribution::Distribution,
+    game::GameRng,
+    object::resource::{ResourceType, Resources},
+};
+
+const DEVELOPMENT_CARD_NO: usize = 5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Tsify, Serialize, Deserialize)]
 pub enum DevelopmentCardType {
     MoveRobber,
     TakeTwoResources,
@@ -11,22 +24,43 @@ pub enum DevelopmentCardType {
     BuildRoads,
 }
 
-#[derive(Debug)]
+pub static DEVELOPMENT_CARDS: [DevelopmentCardType; DEVELOPMENT_CARD_NO] = [
+    DevelopmentCardType::MoveRobber,
+    DevelopmentCardType::TakeTwoResources,
+    DevelopmentCardType::Monopoly,
+    DevelopmentCardType::VictoryPoint,
+    DevelopmentCardType::BuildRoads,
+];
+
+pub type DevelopmentCardDistribution = Distribution<DevelopmentCardType, DEVELOPMENT_CARD_NO>;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DevelopmentCard {
     r#type: DevelopmentCardType,
     played: bool,
+    /// The turn the card was bought on, as it cannot be played until a later turn.
+    bought_on: usize,
 }
 
 impl DevelopmentCard {
-    pub fn new(r#type: DevelopmentCardType) -> Self {
+    pub fn new(r#type: DevelopmentCardType, bought_on: usize) -> Self {
         Self {
             r#type,
             played: false,
+            bought_on,
         }
     }
 
+    pub fn cost() -> ResourceMap {
+        ResourceMap::new(0, 0, 1, 1, 1)
+    }
+
     pub fn get_type(&self) -> DevelopmentCardType {
-        self.r#type.clone()
+        self.r#type
+    }
+
+    pub fn bought_on(&self) -> usize {
+        self.bought_on
     }
 
     pub fn is_played(&self) -> bool {
@@ -46,7 +80,47 @@ impl DevelopmentCard {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+/// The shuffled pile of development cards that have not been bought yet.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DevelopmentCardDeck {
+    cards: Vec<DevelopmentCardType>,
+}
+
+impl DevelopmentCardDeck {
+    pub fn new(distribution: DevelopmentCardDistribution, rng: &mut GameRng) -> Self {
+        let mut cards = Vec::<DevelopmentCardType>::with_capacity(distribution.size());
+        for card in DEVELOPMENT_CARDS {
+            cards.extend(iter::repeat_n(card, distribution.for_obj(card) as usize));
+        }
+
+        cards.shuffle(rng);
+
+        Self { cards }
+    }
+
+    pub fn draw(&mut self) -> Option<DevelopmentCardType> {
+        self.cards.pop()
+    }
+
+    /// A deck in the given order, where the last card is drawn first.
+    pub fn from_cards(cards: Vec<DevelopmentCardType>) -> Self {
+        Self { cards }
+    }
+
+    pub fn cards(&self) -> impl Iterator<Item = DevelopmentCardType> {
+        self.cards.iter().copied()
+    }
+
+    pub fn len(&self) -> usize {
+        self.cards.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cards.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ResourceCard {
     resource_type: ResourceType,
     count: u32,
@@ -91,7 +165,10 @@ impl ResourceCard {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+/// Stored as one card per resource, but (de)serialized as plain counts, so a card can never be read under the field
+/// of another resource.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "ResourceCounts", into = "ResourceCounts")]
 pub struct ResourceMap {
     wood: ResourceCard,
     brick: ResourceCard,
@@ -100,6 +177,39 @@ pub struct ResourceMap {
     ore: ResourceCard,
 }
 
+#[derive(Serialize, Deserialize)]
+struct ResourceCounts {
+    wood: u32,
+    brick: u32,
+    wheat: u32,
+    sheep: u32,
+    ore: u32,
+}
+
+impl From<ResourceCounts> for ResourceMap {
+    fn from(counts: ResourceCounts) -> Self {
+        ResourceMap::new(
+            counts.wood,
+            counts.brick,
+            counts.wheat,
+            counts.sheep,
+            counts.ore,
+        )
+    }
+}
+
+impl From<ResourceMap> for ResourceCounts {
+    fn from(map: ResourceMap) -> Self {
+        ResourceCounts {
+            wood: map.wood.get_count(),
+            brick: map.brick.get_count(),
+            wheat: map.wheat.get_count(),
+            sheep: map.sheep.get_count(),
+            ore: map.ore.get_count(),
+        }
+    }
+}
+
 impl ResourceMap {
     pub fn new(wood: u32, brick: u32, wheat: u32, sheep: u32, ore: u32) -> Self {
         Self {
@@ -131,7 +241,7 @@ impl ResourceMap {
             ResourceType::Brick => self.brick.add(count),
             ResourceType::Wheat => self.wheat.add(count),
             ResourceType::Sheep => self.sheep.add(count),
-            ResourceType::Ore => self.ore.add(count)
+            ResourceType::Ore => self.ore.add(count),
         }
     }
 
@@ -150,6 +260,25 @@ impl ResourceMap {
         }
     }
 
+    /// Counts the total number of cards, regardless of resource. Saturates at `u32::MAX`.
+    pub fn total(&self) -> u32 {
+        self.checked_total().unwrap_or(u32::MAX)
+    }
+
+    /// Counts the total number of cards, or `None` if there are more than a `u32` can hold.
+    pub fn checked_total(&self) -> Option<u32> {
+        Resources::new()
+            .into_iter()
+            .try_fold(0u32, |total, r| total.checked_add(self.get(r).get_count()))
+    }
+
+    /// Returns true if there are at least as many cards of every resource as in `other`.
+    pub fn contains(&self, other: &ResourceMap) -> bool {
+        Resources::new()
+            .into_iter()
+            .all(|r| self.get(r).get_count() >= other.get(r).get_count())
+    }
+
     pub fn get_mut(&mut self, resource: ResourceType) -> &mut ResourceCard {
         match resource {
             ResourceType::Wood => &mut self.wood,
@@ -161,19 +290,38 @@ impl ResourceMap {
     }
 }
 
+/// Saturates at `u32::MAX` for every resource.
 impl Add for ResourceMap {
     type Output = ResourceMap;
     fn add(self, rhs: Self) -> Self::Output {
         ResourceMap::new(
-            self.wood.get_count() + rhs.wood.get_count(),
-            self.brick.get_count() + rhs.brick.get_count(),
-            self.wheat.get_count() + rhs.wheat.get_count(),
-            self.sheep.get_count() + rhs.sheep.get_count(),
-            self.ore.get_count() + rhs.ore.get_count(),
+            self.wood.get_count().saturating_add(rhs.wood.get_count()),
+            self.brick.get_count().saturating_add(rhs.brick.get_count()),
+            self.wheat.get_count().saturating_add(rhs.wheat.get_count()),
+            self.sheep.get_count().saturating_add(rhs.sheep.get_count()),
+            self.ore.get_count().saturating_add(rhs.ore.get_count()),
         )
     }
 }
 
+/// Saturates at zero for every resource; check with `contains` first if that matters.
+impl Sub for ResourceMap {
+    type Output = ResourceMap;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Resources::new()
+            .into_iter()
+            .map(|r| {
+                (
+                    r,
+                    self.get(r)
+                        .get_count()
+                        .saturating_sub(rhs.get(r).get_count()),
+                )
+            })
+            .collect()
+    }
+}
+
 impl FromIterator<(ResourceType, u32)> for ResourceMap {
     fn from_iter<T: IntoIterator<Item = (ResourceType, u32)>>(iter: T) -> Self {
         let mut map = ResourceMap::empty();
@@ -182,4 +330,4 @@ impl FromIterator<(ResourceType, u32)> for ResourceMap {
         }
         map
     }
-}
\ No newline at end of file
+}
@@ -6,6 +6,7 @@ pub mod trade;
 use core::fmt;
 
 use hexgrid::hex::{position::HexPosition, table::HexTable};
+use serde::{Deserialize, Serialize};
 use wasm_bindgen::prelude::wasm_bindgen;
 
 use crate::object::{
@@ -14,7 +15,7 @@ use crate::object::{
     trade::TradeType,
 };
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TileType {
     Resource {
         resource: ResourceType,
@@ -46,7 +47,7 @@ impl TileType {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Robber {
     position: HexPosition,
 }
@@ -76,7 +77,7 @@ impl Robber {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub struct CornerInfo {
     building: Option<Building>,
     trade_type: Option<TradeType>,
@@ -115,7 +116,7 @@ impl CornerInfo {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub struct EdgeInfo {
     transport: Transport,
 }
@@ -130,7 +131,7 @@ impl EdgeInfo {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 #[wasm_bindgen]
 pub struct TileData {
     r#type: TileType,
@@ -1,4 +1,5 @@
 use hexgrid::corner::{position::CornerPosition, table::CornerTable};
+use serde::{Deserialize, Serialize};
 
 use crate::{
     game::player::OwnershipToken,
@@ -8,13 +9,13 @@ use crate::{
     },
 };
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum BuildingType {
     Settlement,
     City,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Building {
     r#type: BuildingType,
     owner: OwnershipToken,
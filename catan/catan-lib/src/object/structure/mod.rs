@@ -1,7 +1,7 @@
 pub mod building;
 pub mod transport;
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use tsify::Tsify;
 use wasm_bindgen::prelude::wasm_bindgen;
 
@@ -10,7 +10,6 @@ use crate::{
     object::{card::ResourceMap, resource::ResourceType},
 };
 
-
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum StructureType {
     Settlement,
@@ -42,10 +41,7 @@ pub struct Structure {
 
 impl Structure {
     pub fn new(r#type: StructureType, owner: OwnershipToken) -> Self {
-        Self {
-            r#type,
-            owner,
-        }
+        Self { r#type, owner }
     }
 
     pub fn cost(&self) -> ResourceMap {
@@ -65,8 +61,7 @@ impl Structure {
     }
 }
 
-
-#[derive(Debug, Clone, Copy, Tsify, Deserialize)]
+#[derive(Debug, Clone, Copy, Tsify, Serialize, Deserialize)]
 #[wasm_bindgen]
 pub struct OwnedStructures {
     settlements: u32,
@@ -111,4 +106,4 @@ impl OwnedStructures {
             StructureType::Boat => self.boats,
         }
     }
-}
\ No newline at end of file
+}
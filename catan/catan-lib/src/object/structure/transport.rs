@@ -1,4 +1,5 @@
 use hexgrid::edge::{position::EdgePosition, table::EdgeTable};
+use serde::{Deserialize, Serialize};
 
 use crate::{
     game::player::OwnershipToken,
@@ -8,13 +9,13 @@ use crate::{
     },
 };
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TransportType {
     Road,
     Boat,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Transport {
     r#type: TransportType,
     owner: OwnershipToken,
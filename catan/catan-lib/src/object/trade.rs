@@ -33,7 +33,7 @@ impl fmt::Display for TradeType {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct TradePort {
     positions: (CornerHeight<Low>, CornerHeight<High>),
     r#type: TradeType,
@@ -71,7 +71,7 @@ static TRADES: [TradeType; TRADE_NO] = [
 
 pub type TradeDistribution = Distribution<TradeType, TRADE_NO>;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TradePortDeck {
     trades: Vec<TradePort>,
 }
@@ -25,4 +25,4 @@ impl WasmTradePort {
 
         Self { positions, trade }
     }
-}
\ No newline at end of file
+}
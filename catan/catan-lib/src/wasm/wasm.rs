@@ -14,10 +14,15 @@ use crate::{
     distribution::Distribution,
     game::{
         Game, GameRng,
-        edition::{BaseEdition, CustomEdition, ExpansionEdition},
+        action::Action,
+        edition::{BaseEdition, CustomEdition, ExpansionEdition, StartingResources},
         error::GameError,
+        event::EventQueue,
+    },
+    object::{
+        card::DevelopmentCardType, resource::ResourceType, structure::OwnedStructures,
+        trade::TradeType,
     },
-    object::{resource::ResourceType, structure::OwnedStructures, trade::TradeType},
     wasm::{
         position::{WasmCornerPosition, WasmEdgePosition, WasmHexPosition},
         resource::WasmTileData,
@@ -28,6 +33,19 @@ use crate::{
 #[wasm_bindgen]
 pub struct WasmInterface {
     game: Game,
+    /// Everything that happened since each seat last asked, so the frontend only has to re-render what changed.
+    events: Vec<EventQueue>,
+}
+
+impl WasmInterface {
+    fn from_game(mut game: Game) -> Self {
+        let seats = (0..).map_while(|i| game.get_player(i)).count();
+        let events: Vec<EventQueue> = (0..seats).map(|_| EventQueue::new()).collect();
+        for queue in &events {
+            game.subscribe(queue.clone());
+        }
+        Self { game, events }
+    }
 }
 
 #[wasm_bindgen]
@@ -41,7 +59,7 @@ impl WasmInterface {
 
         let game = Game::new(BaseEdition, player_count, &mut rng);
 
-        Ok(Self { game })
+        Ok(Self::from_game(game))
     }
 
     pub fn new_expansion(player_count: usize, seed: u64) -> Result<Self, JsError> {
@@ -51,9 +69,11 @@ impl WasmInterface {
 
         let mut rng = GameRng::seed_from_u64(seed);
 
-        Ok(Self {
-            game: Game::new(ExpansionEdition, player_count, &mut rng),
-        })
+        Ok(Self::from_game(Game::new(
+            ExpansionEdition,
+            player_count,
+            &mut rng,
+        )))
     }
 
     pub fn new_custom(
@@ -91,9 +111,76 @@ impl WasmInterface {
             edition = edition.with_owned_structures(owned_structures)
         }
 
-        Ok(Self {
-            game: Game::new(edition.build(), player_count, &mut rng),
-        })
+        if let Some(bank_resources) = wasm.bank_resources {
+            edition = edition.with_bank_resources(bank_resources.into_iter().collect());
+        }
+
+        if let Some(starting_resources) = wasm.starting_resources {
+            edition = edition.with_starting_resources(starting_resources);
+        }
+
+        if let Some(development_distribution) = wasm.development_distr {
+            edition = edition
+                .with_development_card_distribution(Distribution::new(development_distribution));
+        }
+
+        Ok(Self::from_game(Game::new(
+            edition.build(),
+            player_count,
+            &mut rng,
+        )))
+    }
+
+    pub fn load(save: &[u8]) -> Result<Self, JsError> {
+        Ok(Self::from_game(Game::from_bytes(save)?))
+    }
+
+    pub fn load_json(save: &str) -> Result<Self, JsError> {
+        Ok(Self::from_game(Game::from_json(save)?))
+    }
+
+    pub fn save(&self) -> Result<Vec<u8>, JsError> {
+        Ok(self.game.to_bytes()?)
+    }
+
+    pub fn save_json(&self) -> Result<String, JsError> {
+        Ok(self.game.to_json()?)
+    }
+
+    /// The game as the player in the given seat sees it, as JSON. This is what should be shown to that player, since
+    /// saves contain every hand and the order of the deck.
+    pub fn view_for(&self, player_number: usize) -> Result<String, JsError> {
+        let Some(player) = self.game.get_player(player_number) else {
+            return Err(JsError::new("No player in this seat!"));
+        };
+
+        Ok(serde_json::to_string(&self.game.view_for(player.token()))?)
+    }
+
+    /// Applies an action, given as JSON, for the player in the given seat. What it changed can be read with
+    /// `drain_events_for`.
+    pub fn apply(&mut self, player_number: usize, action: &str) -> Result<(), JsError> {
+        let Some(player) = self.game.get_player(player_number) else {
+            return Err(JsError::new("No player in this seat!"));
+        };
+
+        let action: Action = serde_json::from_str(action)?;
+        self.game.apply(player.token(), action)?;
+
+        Ok(())
+    }
+
+    /// Takes every event since the last call for the player in the given seat, oldest first, as a JSON array. Cards
+    /// the player may not see are hidden, see `GameEvent::redacted_for`.
+    pub fn drain_events_for(&self, player_number: usize) -> Result<String, JsError> {
+        let (Some(player), Some(events)) = (
+            self.game.get_player(player_number),
+            self.events.get(player_number),
+        ) else {
+            return Err(JsError::new("No player in this seat!"));
+        };
+
+        Ok(serde_json::to_string(&events.drain_for(player.token()))?)
     }
 
     pub fn get_width(&self) -> u32 {
@@ -179,4 +266,7 @@ pub struct WasmCustomEdition {
     pub trade_distr: Option<[(TradeType, u32); 6]>,
     pub trade_gaps: Option<Vec<u32>>,
     pub owned_structures: Option<OwnedStructures>,
+    pub development_distr: Option<[(DevelopmentCardType, u32); 5]>,
+    pub bank_resources: Option<[(ResourceType, u32); 5]>,
+    pub starting_resources: Option<StartingResources>,
 }
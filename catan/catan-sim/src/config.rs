@@ -0,0 +1,223 @@
+use std::{num::NonZeroUsize, path::PathBuf, str::FromStr};
+
+use catan_lib::{
+    bot::{
+        Bot,
+        heuristic::HeuristicBot,
+        mcts::{MctsBot, SearchBudget},
+        random::RandomBot,
+    },
+    game::{
+        Game, GameRng,
+        edition::{BaseEdition, CustomEdition, ExpansionEdition},
+    },
+};
+use serde::Serialize;
+
+use crate::error::SimError;
+
+pub const USAGE: &str = "\
+Usage: catan-sim [OPTIONS]
+
+Options:
+  --games <N>              Number of games to play [default: 100]
+  --seed <SEED>            Seed of the first game, every following game adds one [default: 0]
+  --edition <EDITION>      base, expansion or custom:<SHORTEST>:<LONGEST> [default: base]
+  --bots <BOTS>            Comma separated bots, one per player: random, heuristic or mcts
+                           [default: heuristic,heuristic,heuristic]
+  --max-actions <N>        Actions after which an unfinished game is stopped [default: 10000]
+  --mcts-iterations <N>    Search iterations per action of the mcts bot [default: 200]
+  --format <FORMAT>        csv or json [default: csv]
+  --output <PATH>          File to write the results to, instead of stdout
+  --help                   Print this message";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BotKind {
+    Random,
+    Heuristic,
+    Mcts,
+}
+
+impl BotKind {
+    pub fn name(self) -> &'static str {
+        match self {
+            BotKind::Random => "random",
+            BotKind::Heuristic => "heuristic",
+            BotKind::Mcts => "mcts",
+        }
+    }
+
+    pub fn create(self, rng: GameRng, mcts_iterations: u32) -> Box<dyn Bot> {
+        match self {
+            BotKind::Random => Box::new(RandomBot::new(rng)),
+            BotKind::Heuristic => Box::new(HeuristicBot::new()),
+            BotKind::Mcts => Box::new(MctsBot::new(rng, SearchBudget::Iterations(mcts_iterations))),
+        }
+    }
+}
+
+impl FromStr for BotKind {
+    type Err = SimError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "random" => Ok(BotKind::Random),
+            "heuristic" => Ok(BotKind::Heuristic),
+            "mcts" => Ok(BotKind::Mcts),
+            _ => Err(SimError::InvalidValue {
+                option: "--bots".to_string(),
+                value: s.to_string(),
+            }),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EditionKind {
+    Base,
+    Expansion,
+    /// A `CustomEdition` with all the defaults of `CustomEditionBuilder` for its size.
+    Custom {
+        shortest: u32,
+        longest: u32,
+    },
+}
+
+impl EditionKind {
+    /// Editions are consumed by the game, and custom ones draw their roll numbers from the RNG, so every game builds
+    /// its own.
+    pub fn new_game(self, player_count: NonZeroUsize, rng: &mut GameRng) -> Game {
+        match self {
+            EditionKind::Base => Game::new(BaseEdition, player_count, rng),
+            EditionKind::Expansion => Game::new(ExpansionEdition, player_count, rng),
+            EditionKind::Custom { shortest, longest } => {
+                let edition = CustomEdition::of_size(shortest, longest, rng).build();
+                Game::new(edition, player_count, rng)
+            }
+        }
+    }
+}
+
+impl FromStr for EditionKind {
+    type Err = SimError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || SimError::InvalidValue {
+            option: "--edition".to_string(),
+            value: s.to_string(),
+        };
+
+        match s {
+            "base" => Ok(EditionKind::Base),
+            "expansion" => Ok(EditionKind::Expansion),
+            _ => {
+                let (shortest, longest) = s
+                    .strip_prefix("custom:")
+                    .and_then(|size| size.split_once(':'))
+                    .ok_or_else(invalid)?;
+                let shortest: u32 = shortest.parse().map_err(|_| invalid())?;
+                let longest: u32 = longest.parse().map_err(|_| invalid())?;
+
+                if shortest == 0 || shortest > longest {
+                    return Err(invalid());
+                }
+
+                Ok(EditionKind::Custom { shortest, longest })
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    Csv,
+    Json,
+}
+
+impl FromStr for OutputFormat {
+    type Err = SimError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "csv" => Ok(OutputFormat::Csv),
+            "json" => Ok(OutputFormat::Json),
+            _ => Err(SimError::InvalidValue {
+                option: "--format".to_string(),
+                value: s.to_string(),
+            }),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Config {
+    pub games: usize,
+    pub seed: u64,
+    pub edition: EditionKind,
+    /// The bots in the order they sit at the table in the first game.
+    pub bots: Vec<BotKind>,
+    pub max_actions: usize,
+    pub mcts_iterations: u32,
+    pub format: OutputFormat,
+    pub output: Option<PathBuf>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            games: 100,
+            seed: 0,
+            edition: EditionKind::Base,
+            bots: vec![BotKind::Heuristic; 3],
+            max_actions: 10_000,
+            mcts_iterations: 200,
+            format: OutputFormat::Csv,
+            output: None,
+        }
+    }
+}
+
+impl Config {
+    /// Parses the command line arguments, without the name of the binary. Returns `None` if help was requested.
+    pub fn from_args(mut args: impl Iterator<Item = String>) -> Result<Option<Self>, SimError> {
+        let mut config = Config::default();
+
+        while let Some(option) = args.next() {
+            if option == "--help" {
+                return Ok(None);
+            }
+
+            let value = args
+                .next()
+                .ok_or_else(|| SimError::MissingValue(option.clone()))?;
+
+            match option.as_str() {
+                "--games" => config.games = parse_number(&option, &value)?,
+                "--seed" => config.seed = parse_number(&option, &value)?,
+                "--edition" => config.edition = value.parse()?,
+                "--bots" => {
+                    config.bots = value.split(',').map(str::parse).collect::<Result<_, _>>()?
+                }
+                "--max-actions" => config.max_actions = parse_number(&option, &value)?,
+                "--mcts-iterations" => config.mcts_iterations = parse_number(&option, &value)?,
+                "--format" => config.format = value.parse()?,
+                "--output" => config.output = Some(PathBuf::from(value)),
+                _ => return Err(SimError::UnknownOption(option)),
+            }
+        }
+
+        if config.bots.len() < 2 {
+            return Err(SimError::NotEnoughBots);
+        }
+
+        Ok(Some(config))
+    }
+}
+
+fn parse_number<T: FromStr>(option: &str, value: &str) -> Result<T, SimError> {
+    value.parse().map_err(|_| SimError::InvalidValue {
+        option: option.to_string(),
+        value: value.to_string(),
+    })
+}
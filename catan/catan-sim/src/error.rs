@@ -0,0 +1,20 @@
+use catan_lib::game::error::ActionError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum SimError {
+    #[error("unknown option {0}")]
+    UnknownOption(String),
+    #[error("missing value for {0}")]
+    MissingValue(String),
+    #[error("invalid value {value:?} for {option}")]
+    InvalidValue { option: String, value: String },
+    #[error("at least two bots are needed for a game")]
+    NotEnoughBots,
+    #[error("a bot chose an illegal action in game {game}: {error}")]
+    IllegalAction { game: usize, error: ActionError },
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+}
@@ -0,0 +1,100 @@
+pub mod config;
+pub mod error;
+pub mod record;
+pub mod sim;
+
+#[cfg(test)]
+pub mod test;
+
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+    process::ExitCode,
+};
+
+use crate::{
+    config::{Config, OutputFormat, USAGE},
+    error::SimError,
+    record::{GameRecord, GameStatus, write_csv, write_json},
+    sim::simulate,
+};
+
+fn main() -> ExitCode {
+    let config = match Config::from_args(std::env::args().skip(1)) {
+        Ok(Some(config)) => config,
+        Ok(None) => {
+            println!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        Err(error) => {
+            eprintln!("error: {error}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
+    match run(&config) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(config: &Config) -> Result<(), SimError> {
+    let mut records = Vec::with_capacity(config.games);
+    for index in 0..config.games {
+        records.push(simulate(config, index)?);
+    }
+
+    let mut out: Box<dyn Write> = match &config.output {
+        Some(path) => Box::new(BufWriter::new(File::create(path)?)),
+        None => Box::new(io::stdout().lock()),
+    };
+
+    match config.format {
+        OutputFormat::Csv => write_csv(&records, &mut out)?,
+        OutputFormat::Json => write_json(&records, &mut out)?,
+    }
+    out.flush()?;
+
+    summarize(config, &records);
+
+    Ok(())
+}
+
+/// Prints how often each bot won to stderr, which keeps stdout free for the results.
+fn summarize(config: &Config, records: &[GameRecord]) {
+    let count = |status| records.iter().filter(|r| r.status == status).count();
+    eprintln!(
+        "{} games, {} stopped after {} actions, {} stalled",
+        records.len(),
+        count(GameStatus::Stopped),
+        config.max_actions,
+        count(GameStatus::Stalled)
+    );
+    for record in records.iter().filter(|r| r.status == GameStatus::Stalled) {
+        eprintln!("game {} (seed {}) stalled", record.game, record.seed);
+    }
+
+    let mut bots = Vec::new();
+    for bot in &config.bots {
+        if !bots.contains(bot) {
+            bots.push(*bot);
+        }
+    }
+
+    for bot in bots {
+        let seats = records
+            .iter()
+            .flat_map(|r| &r.players)
+            .filter(|p| p.bot == bot)
+            .count();
+        let wins = records
+            .iter()
+            .filter_map(|r| r.winner.map(|w| &r.players[w]))
+            .filter(|p| p.bot == bot)
+            .count();
+        eprintln!("{}: won {wins} of {seats} seats", bot.name());
+    }
+}
@@ -0,0 +1,128 @@
+use std::io::Write;
+
+use catan_lib::{
+    game::victory::VictoryPoints,
+    object::{card::ResourceMap, resource::ResourceType},
+};
+use serde::Serialize;
+
+use crate::{config::BotKind, error::SimError};
+
+/// The outcome of a single simulated game.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct GameRecord {
+    pub game: usize,
+    pub seed: u64,
+    pub status: GameStatus,
+    /// The seat of the winner, or `None` if the game ended before anyone won.
+    pub winner: Option<usize>,
+    pub turns: usize,
+    pub actions: usize,
+    pub players: Vec<PlayerRecord>,
+}
+
+/// How a simulated game ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GameStatus {
+    Won,
+    /// Stopped after the maximum number of actions.
+    Stopped,
+    /// The player the game was waiting on had no legal action, so it could not go on.
+    Stalled,
+}
+
+impl GameStatus {
+    pub fn name(self) -> &'static str {
+        match self {
+            GameStatus::Won => "won",
+            GameStatus::Stopped => "stopped",
+            GameStatus::Stalled => "stalled",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PlayerRecord {
+    /// The position in turn order, starting at 0.
+    pub seat: usize,
+    pub bot: BotKind,
+    pub victory_points: VictoryPoints,
+    pub total_victory_points: u32,
+    /// Every resource card the player received from production, including the starting resources.
+    pub production: Production,
+    pub longest_road: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+pub struct Production {
+    pub wood: u32,
+    pub brick: u32,
+    pub wheat: u32,
+    pub sheep: u32,
+    pub ore: u32,
+}
+
+impl From<ResourceMap> for Production {
+    fn from(resources: ResourceMap) -> Self {
+        let count = |resource| resources.get(resource).get_count();
+        Self {
+            wood: count(ResourceType::Wood),
+            brick: count(ResourceType::Brick),
+            wheat: count(ResourceType::Wheat),
+            sheep: count(ResourceType::Sheep),
+            ore: count(ResourceType::Ore),
+        }
+    }
+}
+
+const CSV_HEADER: &str = "game,seed,turns,actions,status,winner,seat,bot,won,settlements,cities,longest_road_points,\
+largest_army_points,revealed_cards,hidden_cards,victory_points,wood,brick,wheat,sheep,ore,longest_road";
+
+/// Writes one row per player of every game, so each row is self-contained.
+pub fn write_csv(records: &[GameRecord], out: &mut impl Write) -> Result<(), SimError> {
+    writeln!(out, "{CSV_HEADER}")?;
+
+    for record in records {
+        let winner = record.winner.map_or(String::new(), |w| w.to_string());
+
+        for player in &record.players {
+            let points = &player.victory_points;
+            let production = &player.production;
+            writeln!(
+                out,
+                "{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{}",
+                record.game,
+                record.seed,
+                record.turns,
+                record.actions,
+                record.status.name(),
+                winner,
+                player.seat,
+                player.bot.name(),
+                record.winner == Some(player.seat),
+                points.settlements,
+                points.cities,
+                points.longest_road,
+                points.largest_army,
+                points.revealed_cards,
+                points.hidden_cards,
+                player.total_victory_points,
+                production.wood,
+                production.brick,
+                production.wheat,
+                production.sheep,
+                production.ore,
+                player.longest_road,
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+pub fn write_json(records: &[GameRecord], out: &mut impl Write) -> Result<(), SimError> {
+    serde_json::to_writer_pretty(&mut *out, records)?;
+    writeln!(out)?;
+    Ok(())
+}
@@ -0,0 +1,109 @@
+use std::num::NonZeroUsize;
+
+use catan_lib::{
+    bot::{Bot, acting_player},
+    game::{GameRng, event::GameEvent, player::OwnershipToken},
+    object::card::ResourceMap,
+};
+use rand::SeedableRng;
+
+use crate::{
+    config::{BotKind, Config},
+    error::SimError,
+    record::{GameRecord, GameStatus, PlayerRecord},
+};
+
+/// Plays the game with the given index. Every game gets its own seed, which determines the board as well as every
+/// choice of the bots, so any single game can be replayed on its own.
+pub fn simulate(config: &Config, index: usize) -> Result<GameRecord, SimError> {
+    let seed = config.seed.wrapping_add(index as u64);
+    let mut rng = GameRng::seed_from_u64(seed);
+
+    let player_count = NonZeroUsize::new(config.bots.len()).ok_or(SimError::NotEnoughBots)?;
+    let mut game = config.edition.new_game(player_count, &mut rng);
+
+    // Seats rotate every game, so no bot always gets to move first.
+    let seats: Vec<BotKind> = (0..config.bots.len())
+        .map(|seat| config.bots[(seat + index) % config.bots.len()])
+        .collect();
+    let mut bots: Vec<Box<dyn Bot>> = seats
+        .iter()
+        .map(|bot| bot.create(GameRng::from_rng(&mut rng), config.mcts_iterations))
+        .collect();
+
+    let tokens: Vec<OwnershipToken> = (0..)
+        .map_while(|i| game.get_player(i))
+        .map(|p| p.token())
+        .collect();
+    let seat_of = |token: OwnershipToken| {
+        tokens
+            .iter()
+            .position(|t| *t == token)
+            .expect("Every player has a seat!")
+    };
+
+    let mut production = vec![ResourceMap::empty(); tokens.len()];
+    let mut actions = 0;
+    let mut stalled = false;
+
+    while actions < config.max_actions {
+        let Some(player) = acting_player(&game) else {
+            break;
+        };
+
+        // A game which cannot go on is recorded as stalled, so the rest of the batch still gets played.
+        let view = game.view_for(player);
+        if view.legal_actions().is_empty() {
+            stalled = true;
+            break;
+        }
+
+        let action = bots[seat_of(player)].choose_action(&view);
+        let events = game
+            .apply(player, action)
+            .map_err(|error| SimError::IllegalAction { game: index, error })?;
+        actions += 1;
+
+        for event in events {
+            if let GameEvent::ResourcesProduced { player, resources } = event {
+                let seat = seat_of(player);
+                production[seat] = production[seat] + resources;
+            }
+        }
+    }
+
+    let players = tokens
+        .iter()
+        .enumerate()
+        .map(|(seat, token)| {
+            let victory_points = game.victory_points(*token);
+            PlayerRecord {
+                seat,
+                bot: seats[seat],
+                victory_points,
+                total_victory_points: victory_points.total(),
+                production: production[seat].into(),
+                longest_road: game.calculate_longest_road(*token),
+            }
+        })
+        .collect();
+
+    let winner = game.winner().map(seat_of);
+    let status = if winner.is_some() {
+        GameStatus::Won
+    } else if stalled {
+        GameStatus::Stalled
+    } else {
+        GameStatus::Stopped
+    };
+
+    Ok(GameRecord {
+        game: index,
+        seed,
+        status,
+        winner,
+        turns: game.turn_number(),
+        actions,
+        players,
+    })
+}
@@ -0,0 +1,71 @@
+use crate::{
+    config::{BotKind, Config, EditionKind, OutputFormat},
+    record::{GameStatus, write_csv},
+    sim::simulate,
+};
+
+fn args(args: &str) -> impl Iterator<Item = String> {
+    args.split_whitespace().map(str::to_string)
+}
+
+#[test]
+fn config_test() {
+    let config = Config::from_args(args(
+        "--games 5 --seed 7 --edition custom:3:5 --bots random,mcts --format json",
+    ))
+    .unwrap()
+    .unwrap();
+
+    assert_eq!(config.games, 5);
+    assert_eq!(config.seed, 7);
+    assert_eq!(
+        config.edition,
+        EditionKind::Custom {
+            shortest: 3,
+            longest: 5
+        }
+    );
+    assert_eq!(config.bots, vec![BotKind::Random, BotKind::Mcts]);
+    assert_eq!(config.format, OutputFormat::Json);
+
+    assert_eq!(Config::from_args(args("--help")).unwrap(), None);
+    assert!(Config::from_args(args("--bots random")).is_err());
+    assert!(Config::from_args(args("--edition custom:5:3")).is_err());
+    assert!(Config::from_args(args("--games")).is_err());
+    assert!(Config::from_args(args("--speed 3")).is_err());
+}
+
+#[test]
+fn simulate_test() {
+    let config = Config::from_args(args("--bots heuristic,random --max-actions 5000"))
+        .unwrap()
+        .unwrap();
+
+    let records: Vec<_> = (0..2).map(|i| simulate(&config, i).unwrap()).collect();
+
+    // Seats rotate between games, and the same seed plays out the same game.
+    assert_eq!(records[0].players[0].bot, BotKind::Heuristic);
+    assert_eq!(records[1].players[0].bot, BotKind::Random);
+    assert_eq!(records[1], simulate(&config, 1).unwrap());
+
+    for record in &records {
+        assert_eq!(record.status, GameStatus::Won);
+        let winner = &record.players[record.winner.unwrap()];
+        assert!(winner.total_victory_points >= 10);
+        let production = winner.production;
+        assert!(
+            production.wood
+                + production.brick
+                + production.wheat
+                + production.sheep
+                + production.ore
+                > 0
+        );
+    }
+
+    let mut csv = Vec::new();
+    write_csv(&records, &mut csv).unwrap();
+    let csv = String::from_utf8(csv).unwrap();
+    assert_eq!(csv.lines().count(), 1 + 2 * 2);
+    assert!(csv.lines().all(|l| l.split(',').count() == 22));
+}
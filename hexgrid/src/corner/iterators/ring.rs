@@ -119,13 +119,14 @@ impl Iterator for CornerRing {
     type Item = (CornerHeight<Low>, CornerHeight<High>);
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.remaining == 0 {
+        // Sides of length 0, as on a board with only one row, are skipped.
+        while self.remaining == 0 {
             self.direction.next()?;
             self.remaining = self.get_remaining();
         }
 
         self.move_in_direction();
-        self.remaining = self.remaining.checked_sub(1)?;
+        self.remaining -= 1;
         Some(self.positions)
     }
 }
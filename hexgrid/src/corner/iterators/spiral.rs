@@ -1 +1,38 @@
-
+use crate::{corner::position::CornerPosition, hex::iterators::spiral::HexSpiral};
+
+/// Every corner of a hex shaped board, ring by ring from the outside in. The corners are visited hex by hex in the
+/// order of `HexSpiral`, clockwise around each hex, skipping the corners already shared with an earlier hex.
+#[derive(Clone)]
+pub struct CornerSpiral {
+    hexes: HexSpiral,
+    visited: Vec<CornerPosition>,
+    pending: Vec<CornerPosition>,
+}
+
+impl CornerSpiral {
+    pub fn new(shortest: u32, longest: u32) -> Self {
+        CornerSpiral {
+            hexes: HexSpiral::new(shortest, longest),
+            visited: Vec::new(),
+            pending: Vec::new(),
+        }
+    }
+}
+
+impl Iterator for CornerSpiral {
+    type Item = CornerPosition;
+
+    fn next(&mut self) -> Option<CornerPosition> {
+        while self.pending.is_empty() {
+            let hex = self.hexes.next()?;
+            for corner in CornerPosition::around(hex).into_iter().rev() {
+                if !self.visited.contains(&corner) {
+                    self.visited.push(corner);
+                    self.pending.push(corner);
+                }
+            }
+        }
+
+        self.pending.pop()
+    }
+}
@@ -9,3 +9,6 @@ pub trait Corner {
     fn neighboring_hex(&self) -> [HexPosition; 3];
     fn neighboring_corners(&self) -> Vec<Box<dyn Corner>>;
 }
+
+#[cfg(test)]
+pub mod test;
@@ -7,7 +7,7 @@ use crate::{
         position::{high::High, low::Low},
     },
     edge::position::{EdgeOrientation, EdgePosition},
-    hex::position::HexPosition,
+    hex::position::{HexPosition, ReflectionAxis},
 };
 
 pub mod center;
@@ -18,11 +18,43 @@ pub mod op_mul;
 pub mod op_sub;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "UncheckedCornerPosition")
+)]
 pub enum CornerPosition {
     High(CornerHeight<High>),
     Low(CornerHeight<Low>),
 }
 
+/// A corner as it is read, before it is checked to be the corner of a hex.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(rename = "CornerPosition")]
+enum UncheckedCornerPosition {
+    High(CornerHeight<High>),
+    Low(CornerHeight<Low>),
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<UncheckedCornerPosition> for CornerPosition {
+    type Error = &'static str;
+
+    fn try_from(value: UncheckedCornerPosition) -> Result<Self, Self::Error> {
+        let position = match value {
+            UncheckedCornerPosition::High(p) => Self::High(p),
+            UncheckedCornerPosition::Low(p) => Self::Low(p),
+        };
+
+        if !position.is_valid() {
+            return Err("corner coordinates do not match a corner of any hex");
+        }
+
+        Ok(position)
+    }
+}
+
 impl CornerPosition {
     pub fn position(&self) -> &dyn Corner {
         match self {
@@ -125,20 +157,93 @@ impl CornerPosition {
                 ]
             }
             Self::Low(p) => {
-                let [up, down_left, down_right] = p.neighboring_hex();
+                let [up, down_left, _] = p.neighboring_hex();
                 [
                     (up + EdgeOrientation::BOTTOM_RIGHT).into(),
                     (down_left + EdgeOrientation::RIGHT).into(),
-                    (down_right + EdgeOrientation::TOP_RIGHT).into(),
+                    (down_left + EdgeOrientation::TOP_RIGHT).into(),
                 ]
             }
         }
     }
 
+    /// The six corners of a hex, clockwise from the top.
+    pub fn around(hex: HexPosition) -> [CornerPosition; 6] {
+        [
+            (hex + CornerHeight::TOP).into(),
+            (hex + CornerHeight::TOP_RIGHT).into(),
+            (hex + CornerHeight::BOTTOM_RIGHT).into(),
+            (hex + CornerHeight::BOTTOM).into(),
+            (hex + CornerHeight::BOTTOM_LEFT).into(),
+            (hex + CornerHeight::TOP_LEFT).into(),
+        ]
+    }
+
     pub fn is_neighbor(&self, other: Self) -> bool {
         (self.horizontal_distance(other).abs() + self.vertical_distance(other).abs()) == 2
     }
 
+    /// The number of edges on the shortest path from `self` to `other`.
+    pub fn distance(&self, other: Self) -> u32 {
+        let [a, b, c] = self.triangle_coordinates();
+        let [x, y, z] = other.triangle_coordinates();
+
+        (a.abs_diff(x) + b.abs_diff(y) + c.abs_diff(z)) / 6
+    }
+
+    /// Every corner at most `steps` edges away, including `self`, ordered by distance.
+    pub fn corners_within(self, steps: u32) -> Vec<CornerPosition> {
+        let mut corners = vec![self];
+        let mut frontier = 0;
+        for _ in 0..steps {
+            let end = corners.len();
+            for i in frontier..end {
+                for neighbor in corners[i].neighboring_corners() {
+                    if !corners.contains(&neighbor) {
+                        corners.push(neighbor);
+                    }
+                }
+            }
+            frontier = end;
+        }
+
+        corners
+    }
+
+    /// Rotates by 60 degrees around the center of a hex for every turn, clockwise for positive turns. Every turn swaps
+    /// `High` and `Low` corners.
+    pub fn rotate(self, center: HexPosition, turns: i32) -> Self {
+        Self::from_neighboring_hex(self.neighboring_hex().map(|h| h.rotate(center, turns)))
+    }
+
+    /// Mirrors across the axis through the center of a hex, which swaps `High` and `Low` corners.
+    pub fn reflect(self, center: HexPosition, axis: ReflectionAxis) -> Self {
+        Self::from_neighboring_hex(self.neighboring_hex().map(|h| h.reflect(center, axis)))
+    }
+
+    /// Finds the corner the three hexes meet at, which must be one of the corners of the first.
+    fn from_neighboring_hex(hexes: [HexPosition; 3]) -> Self {
+        let [hex, ..] = hexes;
+        Self::around(hex)
+            .into_iter()
+            .find(|corner| {
+                corner
+                    .neighboring_hex()
+                    .iter()
+                    .all(|h| hexes.contains(h))
+            })
+            .expect("Three hexes which meet at a corner!")
+    }
+
+    /// Any coordinates can be read, but only those in range which lie on a corner of their own neighboring hexes
+    /// make up a corner.
+    #[cfg(feature = "serde")]
+    fn is_valid(&self) -> bool {
+        self.rights().unsigned_abs() <= crate::hex::position::MAX_COORDINATE
+            && self.downs().unsigned_abs() <= crate::hex::position::MAX_COORDINATE
+            && Self::around(self.neighboring_hex()[0]).contains(self)
+    }
+
     fn rights(&self) -> i32 {
         match self {
             Self::High(p) => p.rights,
@@ -152,6 +257,23 @@ impl CornerPosition {
             Self::Low(p) => p.downs,
         }
     }
+
+    /// Every corner sits in a triangle between three hex centers. In the triangle coordinates every step to a
+    /// neighboring corner changes exactly one coordinate by one, so the distance is the sum of the differences. The
+    /// coordinates are scaled by 6 to stay integers.
+    fn triangle_coordinates(&self) -> [i32; 3] {
+        let offset = match self {
+            Self::High(_) => 0,
+            Self::Low(_) => -2,
+        };
+        let (rights, downs) = (self.rights(), self.downs());
+
+        [
+            2 * downs + offset,
+            3 * rights - downs + offset,
+            -3 * rights - downs + offset,
+        ]
+    }
 }
 
 impl fmt::Display for CornerPosition {
@@ -172,9 +294,11 @@ impl fmt::Display for CornerPosition {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CornerHeight<H> {
     rights: i32,
     downs: i32,
+    #[cfg_attr(feature = "serde", serde(skip))]
     height: PhantomData<H>,
 }
 
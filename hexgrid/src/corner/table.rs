@@ -1,16 +1,19 @@
 use std::ops::{Index, IndexMut};
 
 use crate::{
-    corner::{bounds::CornerArea, position::CornerPosition},
-    hex::{position::HexPosition, table::HexTable},
+    corner::position::CornerPosition,
+    hex::{bounds::HexBounds, position::HexPosition, shape::HexShape, table::HexTable},
 };
 
 use super::{bounds::CornerBounds, position::CornerHeight};
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CornerTable<T> {
     data: HexTable<(Option<T>, Option<T>)>,
     bounds: CornerBounds,
+    /// Limits the table to the corners touching a hex of the shape. Without one, every corner in bounds is taken.
+    shape: Option<HexShape>,
 }
 
 impl CornerPosition {
@@ -25,15 +28,57 @@ impl CornerPosition {
 impl<T> CornerTable<T> {
     pub fn new(bounds: CornerBounds) -> Self {
         let data = HexTable::new(bounds.get_hex_bounds());
-        CornerTable { data, bounds }
+        CornerTable {
+            data,
+            bounds,
+            shape: None,
+        }
+    }
+
+    /// A dense table which only accepts the corners of the hexes in the shape.
+    pub fn with_shape(shape: HexShape) -> Self {
+        let bounds = CornerBounds::new(&shape.bounds());
+        let data = HexTable::new(storage_bounds(&shape));
+        CornerTable {
+            data,
+            bounds,
+            shape: Some(shape),
+        }
+    }
+
+    /// A sparse table which only accepts the corners of the hexes in the shape, see `HexTable::sparse`.
+    pub fn sparse(shape: HexShape) -> Self {
+        let bounds = CornerBounds::new(&shape.bounds());
+        let data = HexTable::sparse(HexShape::Rectangle(storage_bounds(&shape)));
+        CornerTable {
+            data,
+            bounds,
+            shape: Some(shape),
+        }
     }
 
     pub fn get_bounds(&self) -> &CornerBounds {
         &self.bounds
     }
 
+    pub fn get_shape(&self) -> Option<&HexShape> {
+        self.shape.as_ref()
+    }
+
+    /// A shaped table takes every corner touching one of its hexes, without going through the bounds, which miss
+    /// corners of shapes starting on an odd row.
+    pub fn contains(&self, position: CornerPosition) -> bool {
+        match &self.shape {
+            Some(shape) => position
+                .neighboring_hex()
+                .iter()
+                .any(|hex| shape.contains(*hex)),
+            None => self.bounds.contains(position),
+        }
+    }
+
     pub fn get(&self, position: CornerPosition) -> Option<&T> {
-        if !self.bounds.contains(position) {
+        if !self.contains(position) {
             return None;
         }
 
@@ -48,7 +93,7 @@ impl<T> CornerTable<T> {
     }
 
     pub fn get_mut(&mut self, position: CornerPosition) -> Option<&mut T> {
-        if !self.bounds.contains(position) {
+        if !self.contains(position) {
             return None;
         }
 
@@ -63,7 +108,7 @@ impl<T> CornerTable<T> {
     }
 
     pub fn set(&mut self, position: CornerPosition, data: T) -> Result<(), ()> {
-        if !self.bounds.contains(position) {
+        if !self.contains(position) {
             return Err(());
         }
 
@@ -81,8 +126,17 @@ impl<T> CornerTable<T> {
         Ok(())
     }
 
-    pub fn positions(&self) -> CornerArea<'_> {
-        self.bounds.area()
+    /// Every corner of the table, row by row of the hexes they are stored with.
+    pub fn positions(&self) -> impl Iterator<Item = CornerPosition> + '_ {
+        self.data
+            .get_bounds()
+            .area()
+            .flat_map(|hex| {
+                CornerPosition::around(hex)
+                    .into_iter()
+                    .filter(move |corner| corner.structural_owner() == hex)
+            })
+            .filter(|corner| self.contains(*corner))
     }
 
     pub fn data(&self) -> CornerData<'_, T> {
@@ -90,6 +144,23 @@ impl<T> CornerTable<T> {
     }
 }
 
+/// The rectangle around the hexes the corners of the shape are stored with, which reaches past the shape itself.
+fn storage_bounds(shape: &HexShape) -> HexBounds {
+    let hexes = shape.bounds();
+    let mut owners = hexes
+        .area()
+        .filter(|hex| shape.contains(*hex))
+        .flat_map(CornerPosition::around)
+        .map(|corner| corner.structural_owner());
+
+    let mut bounds = HexBounds::at(owners.next().unwrap_or(hexes.get_top_left()));
+    for owner in owners {
+        bounds.expand(owner);
+    }
+
+    bounds
+}
+
 impl<T> Index<CornerPosition> for CornerTable<T> {
     type Output = T;
 
@@ -108,12 +179,12 @@ impl<T> IndexMut<CornerPosition> for CornerTable<T> {
 
 pub struct CornerData<'a, T> {
     parent: &'a CornerTable<T>,
-    area: CornerArea<'a>,
+    area: Box<dyn Iterator<Item = CornerPosition> + 'a>,
 }
 
 impl<'a, T> CornerData<'a, T> {
     fn new(parent: &'a CornerTable<T>) -> Self {
-        let area = parent.bounds.area();
+        let area = Box::new(parent.positions());
         CornerData { parent, area }
     }
 }
@@ -0,0 +1,216 @@
+use crate::{
+    corner::{
+        iterators::spiral::CornerSpiral,
+        position::{CornerHeight, CornerPosition},
+        table::CornerTable,
+    },
+    hex::{
+        bounds::HexBounds,
+        iterators::spiral::HexSpiral,
+        position::{HexPosition, ReflectionAxis},
+        shape::HexShape,
+    },
+};
+
+#[test]
+fn test_corner_neighboring_edges() {
+    for hex in HexPosition::ORIGIN.hexes_within(3) {
+        for corner in CornerPosition::around(hex) {
+            let edges = corner.neighboring_edges();
+            for (i, edge) in edges.iter().enumerate() {
+                assert!(edge.neighboring_corners().contains(&corner));
+                assert!(!edges[..i].contains(edge));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_corner_distance() {
+    let start = HexPosition::ORIGIN + HexPosition::DOWN_LEFT;
+    let corners: [CornerPosition; 2] = [
+        (start + CornerHeight::TOP).into(),
+        (start + CornerHeight::BOTTOM).into(),
+    ];
+
+    for corner in corners {
+        let mut previous = Vec::new();
+        for steps in 0..5 {
+            let within = corner.corners_within(steps);
+            for other in within.iter().filter(|c| !previous.contains(*c)) {
+                assert_eq!(corner.distance(*other), steps);
+                assert_eq!(other.distance(corner), steps);
+            }
+
+            previous = within;
+        }
+
+        // 3 more corners at every distance than at the one before: 1 + 3 + 6 + 9 + 12.
+        assert_eq!(previous.len(), 31);
+    }
+}
+
+#[test]
+fn test_corner_symmetry() {
+    let center = HexPosition::ORIGIN + HexPosition::UP_RIGHT;
+    let corner: CornerPosition = (center + HexPosition::RIGHT + CornerHeight::TOP).into();
+
+    let mut rotated = corner;
+    for turns in 1..=6 {
+        let next = rotated.rotate(center, 1);
+        assert_ne!(
+            matches!(next, CornerPosition::High(_)),
+            matches!(rotated, CornerPosition::High(_))
+        );
+        assert_eq!(next, corner.rotate(center, turns));
+        for other in corner.corners_within(3) {
+            assert_eq!(
+                next.distance(other.rotate(center, turns)),
+                rotated.distance(other.rotate(center, turns - 1))
+            );
+        }
+
+        let mut hexes = rotated.neighboring_hex().map(|h| h.rotate(center, 1));
+        let mut expected = next.neighboring_hex();
+        hexes.sort_by_key(|h| {
+            (
+                h.vertical_displacement(center),
+                h.raw_horizontal_displacement(center),
+            )
+        });
+        expected.sort_by_key(|h| {
+            (
+                h.vertical_displacement(center),
+                h.raw_horizontal_displacement(center),
+            )
+        });
+        assert_eq!(hexes, expected);
+
+        rotated = next;
+    }
+    assert_eq!(rotated, corner);
+
+    for axis in [
+        ReflectionAxis::Horizontal,
+        ReflectionAxis::Rising,
+        ReflectionAxis::Falling,
+    ] {
+        let reflected = corner.reflect(center, axis);
+        assert!(matches!(reflected, CornerPosition::Low(_)));
+        assert_eq!(reflected.reflect(center, axis), corner);
+    }
+}
+
+#[test]
+fn test_corner_spiral() {
+    for (shortest, longest, count) in [(3, 5, 54), (3, 6, 80), (2, 3, 24)] {
+        let corners: Vec<CornerPosition> = CornerSpiral::new(shortest, longest).collect();
+        assert_eq!(corners.len(), count);
+
+        for (i, corner) in corners.iter().enumerate() {
+            assert!(!corners[..i].contains(corner));
+        }
+
+        for hex in HexSpiral::new(shortest, longest) {
+            assert!(
+                CornerPosition::around(hex)
+                    .iter()
+                    .all(|c| corners.contains(c))
+            );
+        }
+    }
+}
+
+#[test]
+fn test_shaped_corner_table() {
+    let shape = HexShape::Hexagon {
+        top_left: HexPosition::ORIGIN,
+        shortest: 3,
+        longest: 5,
+    };
+    let corners: Vec<CornerPosition> = CornerSpiral::new(3, 5).collect();
+
+    for mut table in [
+        CornerTable::<usize>::with_shape(shape.clone()),
+        CornerTable::<usize>::sparse(shape.clone()),
+    ] {
+        let positions: Vec<CornerPosition> = table.positions().collect();
+        assert_eq!(positions.len(), corners.len());
+        assert!(positions.iter().all(|c| corners.contains(c)));
+
+        for (i, corner) in corners.iter().enumerate() {
+            table.set(*corner, i).expect("Corner is on the board!");
+        }
+        for (i, corner) in corners.iter().enumerate() {
+            assert_eq!(table[*corner], i);
+        }
+
+        let outside = corners[0].neighboring_corners()[0].go_left().go_left();
+        assert!(!table.contains(outside));
+        assert!(table.set(outside, 0).is_err());
+        assert_eq!(table.data().count(), corners.len());
+    }
+}
+
+#[test]
+fn test_odd_row_corner_table() {
+    let top_left = HexPosition::ORIGIN + HexPosition::DOWN_LEFT;
+    let mut bounds = HexBounds::at(top_left);
+    bounds.expand(top_left + HexPosition::RIGHT * 3 + HexPosition::DOWN_LEFT * 2);
+
+    for shape in [
+        HexShape::Hexagon {
+            top_left,
+            shortest: 3,
+            longest: 5,
+        },
+        HexShape::Rectangle(bounds),
+    ] {
+        let mut corners: Vec<CornerPosition> = Vec::new();
+        for hex in shape.bounds().area().filter(|p| shape.contains(*p)) {
+            for corner in CornerPosition::around(hex) {
+                if !corners.contains(&corner) {
+                    corners.push(corner);
+                }
+            }
+        }
+
+        for mut table in [
+            CornerTable::<usize>::with_shape(shape.clone()),
+            CornerTable::<usize>::sparse(shape.clone()),
+        ] {
+            let positions: Vec<CornerPosition> = table.positions().collect();
+            assert_eq!(positions.len(), corners.len());
+            assert!(positions.iter().all(|c| corners.contains(c)));
+
+            for (i, corner) in corners.iter().enumerate() {
+                table.set(*corner, i).expect("Corner is on the board!");
+            }
+            for (i, corner) in corners.iter().enumerate() {
+                assert_eq!(table[*corner], i);
+            }
+            assert_eq!(table.data().count(), corners.len());
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_corner_deserialization() {
+    for hex in HexPosition::ORIGIN.hexes_within(3) {
+        for corner in CornerPosition::around(hex) {
+            let json = serde_json::to_string(&corner).expect("Corner serializes!");
+            let restored: CornerPosition = serde_json::from_str(&json).expect("Corner is valid!");
+            assert_eq!(restored, corner);
+        }
+    }
+
+    // Coordinates between corners, and ones far enough out to overflow once a hex is added.
+    for json in [
+        r#"{"High":{"rights":0,"downs":0}}"#,
+        r#"{"Low":{"rights":1,"downs":0}}"#,
+        r#"{"High":{"rights":2147483647,"downs":2147483647}}"#,
+    ] {
+        assert!(serde_json::from_str::<CornerPosition>(json).is_err());
+    }
+}
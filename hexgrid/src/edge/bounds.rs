@@ -9,6 +9,7 @@ use crate::{
 use super::position::EdgeOrientation;
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EdgeBounds {
     bounds: HexBounds,
 }
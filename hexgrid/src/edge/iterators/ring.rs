@@ -1 +1,62 @@
-
+use crate::{
+    corner::{iterators::ring::CornerRing, position::CornerPosition},
+    edge::position::EdgePosition,
+};
+
+/// The edges around the outside of a hex shaped board, clockwise from the top left. They join the corners of
+/// `CornerRing`, one after the other.
+#[derive(Debug, Clone, Copy)]
+pub struct EdgeRing {
+    corners: CornerRing,
+    first: Option<CornerPosition>,
+    previous: Option<CornerPosition>,
+    pending: Option<CornerPosition>,
+}
+
+impl EdgeRing {
+    pub fn new(shortest: u32, longest: u32) -> Self {
+        EdgeRing {
+            corners: CornerRing::new(shortest, longest),
+            first: None,
+            previous: None,
+            pending: None,
+        }
+    }
+
+    fn next_corner(&mut self) -> Option<CornerPosition> {
+        if let Some(corner) = self.pending.take() {
+            return Some(corner);
+        }
+
+        let (low, high) = self.corners.next()?;
+        self.pending = Some(high.into());
+        Some(low.into())
+    }
+}
+
+impl Iterator for EdgeRing {
+    type Item = EdgePosition;
+
+    fn next(&mut self) -> Option<EdgePosition> {
+        let previous = match self.previous {
+            Some(previous) => previous,
+            None => {
+                let first = self.next_corner()?;
+                self.first = Some(first);
+                first
+            }
+        };
+
+        // Once the corners run out, the last edge closes the ring back to the first corner.
+        let next = match self.next_corner() {
+            Some(next) => next,
+            None => self.first.take()?,
+        };
+        self.previous = Some(next);
+
+        previous
+            .neighboring_edges()
+            .into_iter()
+            .find(|edge| edge.neighboring_corners().contains(&next))
+    }
+}
@@ -1 +1,38 @@
-
+use crate::{edge::position::EdgePosition, hex::iterators::spiral::HexSpiral};
+
+/// Every edge of a hex shaped board, ring by ring from the outside in. The edges are visited hex by hex in the
+/// order of `HexSpiral`, clockwise around each hex, skipping the edges already shared with an earlier hex.
+#[derive(Clone)]
+pub struct EdgeSpiral {
+    hexes: HexSpiral,
+    visited: Vec<EdgePosition>,
+    pending: Vec<EdgePosition>,
+}
+
+impl EdgeSpiral {
+    pub fn new(shortest: u32, longest: u32) -> Self {
+        EdgeSpiral {
+            hexes: HexSpiral::new(shortest, longest),
+            visited: Vec::new(),
+            pending: Vec::new(),
+        }
+    }
+}
+
+impl Iterator for EdgeSpiral {
+    type Item = EdgePosition;
+
+    fn next(&mut self) -> Option<EdgePosition> {
+        while self.pending.is_empty() {
+            let hex = self.hexes.next()?;
+            for edge in EdgePosition::around(hex).into_iter().rev() {
+                if !self.visited.contains(&edge) {
+                    self.visited.push(edge);
+                    self.pending.push(edge);
+                }
+            }
+        }
+
+        self.pending.pop()
+    }
+}
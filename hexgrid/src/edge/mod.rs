@@ -8,3 +8,6 @@ pub mod table;
 pub trait Edge {
     fn neighboring_hex(&self) -> [HexPosition; 2];
 }
+
+#[cfg(test)]
+pub mod test;
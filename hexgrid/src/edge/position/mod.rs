@@ -7,7 +7,7 @@ use crate::{
         Edge,
         position::{even::Even, odd::Odd, positive::Positive},
     },
-    hex::position::HexPosition,
+    hex::position::{HexPosition, ReflectionAxis},
 };
 
 pub mod even;
@@ -19,12 +19,46 @@ pub mod op_sub;
 pub mod positive;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "UncheckedEdgePosition")
+)]
 pub enum EdgePosition {
     Even(EdgeOrientation<Even>),
     Odd(EdgeOrientation<Odd>),
     Positive(EdgeOrientation<Positive>),
 }
 
+/// An edge as it is read, before it is checked to be the edge of a hex.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(rename = "EdgePosition")]
+enum UncheckedEdgePosition {
+    Even(EdgeOrientation<Even>),
+    Odd(EdgeOrientation<Odd>),
+    Positive(EdgeOrientation<Positive>),
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<UncheckedEdgePosition> for EdgePosition {
+    type Error = &'static str;
+
+    fn try_from(value: UncheckedEdgePosition) -> Result<Self, Self::Error> {
+        let position = match value {
+            UncheckedEdgePosition::Even(p) => Self::Even(p),
+            UncheckedEdgePosition::Odd(p) => Self::Odd(p),
+            UncheckedEdgePosition::Positive(p) => Self::Positive(p),
+        };
+
+        if !position.is_valid() {
+            return Err("edge coordinates do not match an edge of any hex");
+        }
+
+        Ok(position)
+    }
+}
+
 impl EdgePosition {
     pub fn position(&self) -> &dyn Edge {
         match self {
@@ -44,7 +78,7 @@ impl EdgePosition {
 
     /// If `other` does not form a gap with `self`, will return Err.
     pub fn find_gap(&self, other: Self) -> Result<Self, ()> {
-        if self.distance(other) != 4 {
+        if self.raw_distance(other) != 4 {
             return Err(());
         }
 
@@ -96,8 +130,20 @@ impl EdgePosition {
         }
     }
 
+    /// The six edges of a hex, clockwise from the top right.
+    pub fn around(hex: HexPosition) -> [EdgePosition; 6] {
+        [
+            (hex + EdgeOrientation::TOP_RIGHT).into(),
+            (hex + EdgeOrientation::RIGHT).into(),
+            (hex + EdgeOrientation::BOTTOM_RIGHT).into(),
+            (hex + EdgeOrientation::BOTTOM_LEFT).into(),
+            (hex + EdgeOrientation::LEFT).into(),
+            (hex + EdgeOrientation::TOP_LEFT).into(),
+        ]
+    }
+
     pub fn is_neighbor(&self, other: Self) -> bool {
-        (self.distance(other)) == 2 && self.rights() != other.rights()
+        (self.raw_distance(other)) == 2 && self.rights() != other.rights()
     }
 
     /// Returns Ok if first and second are both neighbors of self. Otherwise, it returns Err.
@@ -108,14 +154,70 @@ impl EdgePosition {
 
         if first.is_neighbor(second) {
             return Ok(true);
-        } else if first.distance(second) == 4 {
+        } else if first.raw_distance(second) == 4 {
             return Ok(false);
         }
 
         Err(())
     }
 
-    fn distance(&self, other: Self) -> i32 {
+    /// The number of steps between edges sharing a corner it takes to get from `self` to `other`.
+    pub fn distance(&self, other: Self) -> u32 {
+        if self == &other {
+            return 0;
+        }
+
+        let mut closest = u32::MAX;
+        for from in self.neighboring_corners() {
+            for to in other.neighboring_corners() {
+                closest = closest.min(from.distance(to));
+            }
+        }
+
+        closest + 1
+    }
+
+    /// Every edge at most `steps` steps away, including `self`, ordered by distance.
+    pub fn edges_within(self, steps: u32) -> Vec<EdgePosition> {
+        let mut edges = vec![self];
+        let mut frontier = 0;
+        for _ in 0..steps {
+            let end = edges.len();
+            for i in frontier..end {
+                for neighbor in edges[i].neighboring_edges() {
+                    if !edges.contains(&neighbor) {
+                        edges.push(neighbor);
+                    }
+                }
+            }
+            frontier = end;
+        }
+
+        edges
+    }
+
+    /// Rotates by 60 degrees around the center of a hex for every turn, clockwise for positive turns. Every turn cycles
+    /// `Even`, `Odd` and `Positive` edges, in that order.
+    pub fn rotate(self, center: HexPosition, turns: i32) -> Self {
+        Self::from_neighboring_hex(self.neighboring_hex().map(|h| h.rotate(center, turns)))
+    }
+
+    /// Mirrors across the axis through the center of a hex. Edges perpendicular to the axis keep their orientation,
+    /// while the other two swap.
+    pub fn reflect(self, center: HexPosition, axis: ReflectionAxis) -> Self {
+        Self::from_neighboring_hex(self.neighboring_hex().map(|h| h.reflect(center, axis)))
+    }
+
+    /// Finds the edge between the two hexes, which must be neighbors.
+    fn from_neighboring_hex(hexes: [HexPosition; 2]) -> Self {
+        let [hex, ..] = hexes;
+        Self::around(hex)
+            .into_iter()
+            .find(|edge| edge.neighboring_hex().iter().all(|h| hexes.contains(h)))
+            .expect("Two neighboring hexes!")
+    }
+
+    fn raw_distance(&self, other: Self) -> i32 {
         self.horizontal_distance(other).abs() + self.vertical_distance(other).abs()
     }
 
@@ -143,6 +245,15 @@ impl EdgePosition {
         }
     }
 
+    /// Any coordinates can be read, but only those in range which lie on an edge of their own neighboring hexes make
+    /// up an edge.
+    #[cfg(feature = "serde")]
+    fn is_valid(&self) -> bool {
+        self.rights().unsigned_abs() <= crate::hex::position::MAX_COORDINATE
+            && self.downs().unsigned_abs() <= crate::hex::position::MAX_COORDINATE
+            && Self::around(self.neighboring_hex()[0]).contains(self)
+    }
+
     fn rights(&self) -> i32 {
         match self {
             Self::Even(p) => p.rights,
@@ -183,9 +294,11 @@ impl fmt::Display for EdgePosition {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EdgeOrientation<T> {
     rights: i32,
     downs: i32,
+    #[cfg_attr(feature = "serde", serde(skip))]
     r#type: PhantomData<T>,
 }
 
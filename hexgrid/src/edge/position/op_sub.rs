@@ -30,13 +30,8 @@ macro_rules! edge_to_hex {
                 let rights = self.rights - rhs.rights;
                 let downs = self.downs - rhs.downs;
 
-                if rights.signum() == downs.signum() {
-                    HexPosition::DOWN_RIGHT * ((downs - 1) / 2)
-                        + HexPosition::RIGHT * ((rights - downs) / 4)
-                } else {
-                    HexPosition::DOWN_LEFT * ((downs - 1) / 2)
-                        + HexPosition::RIGHT * ((rights - downs) / 4)
-                }
+                HexPosition::DOWN_RIGHT * ((downs - 1) / 2)
+                    + HexPosition::RIGHT * ((rights - downs) / 4)
             }
         }
     };
@@ -2,15 +2,18 @@ use std::ops::{Index, IndexMut};
 
 use crate::{
     edge::position::EdgePosition,
-    hex::{position::HexPosition, table::HexTable},
+    hex::{bounds::HexBounds, position::HexPosition, shape::HexShape, table::HexTable},
 };
 
 use super::{bounds::EdgeBounds, position::EdgeOrientation};
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EdgeTable<T> {
     data: HexTable<(Option<T>, Option<T>, Option<T>)>,
     bounds: EdgeBounds,
+    /// Limits the table to the edges touching a hex of the shape. Without one, every edge in bounds is taken.
+    shape: Option<HexShape>,
 }
 
 impl EdgePosition {
@@ -28,6 +31,27 @@ impl<T> EdgeTable<T> {
         EdgeTable {
             data: HexTable::new(bounds.get_hex_bounds()),
             bounds,
+            shape: None,
+        }
+    }
+
+    /// A dense table which only accepts the edges of the hexes in the shape.
+    pub fn with_shape(shape: HexShape) -> Self {
+        let bounds = EdgeBounds::new(&shape.bounds());
+        EdgeTable {
+            data: HexTable::new(storage_bounds(&shape)),
+            bounds,
+            shape: Some(shape),
+        }
+    }
+
+    /// A sparse table which only accepts the edges of the hexes in the shape, see `HexTable::sparse`.
+    pub fn sparse(shape: HexShape) -> Self {
+        let bounds = EdgeBounds::new(&shape.bounds());
+        EdgeTable {
+            data: HexTable::sparse(HexShape::Rectangle(storage_bounds(&shape))),
+            bounds,
+            shape: Some(shape),
         }
     }
 
@@ -35,8 +59,24 @@ impl<T> EdgeTable<T> {
         &self.bounds
     }
 
+    pub fn get_shape(&self) -> Option<&HexShape> {
+        self.shape.as_ref()
+    }
+
+    /// A shaped table takes every edge touching one of its hexes, without going through the bounds, which miss
+    /// edges of shapes starting on an odd row.
+    pub fn contains(&self, position: EdgePosition) -> bool {
+        match &self.shape {
+            Some(shape) => position
+                .neighboring_hex()
+                .iter()
+                .any(|hex| shape.contains(*hex)),
+            None => self.bounds.contains(position),
+        }
+    }
+
     pub fn get(&self, position: EdgePosition) -> Option<&T> {
-        if !self.bounds.contains(position) {
+        if !self.contains(position) {
             return None;
         }
 
@@ -52,7 +92,7 @@ impl<T> EdgeTable<T> {
     }
 
     pub fn get_mut(&mut self, position: EdgePosition) -> Option<&mut T> {
-        if !self.bounds.contains(position) {
+        if !self.contains(position) {
             return None;
         }
 
@@ -68,12 +108,13 @@ impl<T> EdgeTable<T> {
     }
 
     pub fn set(&mut self, position: EdgePosition, data: T) -> Result<(), ()> {
-        if !self.bounds.contains(position) {
+        if !self.contains(position) {
             return Err(());
         }
-        
+
         if self.data.get_mut(position.structural_owner()).is_none() {
-            self.data.set(position.structural_owner(), (None, None, None))?;
+            self.data
+                .set(position.structural_owner(), (None, None, None))?;
         }
 
         let d = self.data.get_mut(position.structural_owner()).ok_or(())?;
@@ -87,11 +128,44 @@ impl<T> EdgeTable<T> {
         Ok(())
     }
 
+    /// Every edge of the table, row by row of the hexes they are stored with.
+    pub fn positions(&self) -> impl Iterator<Item = EdgePosition> + '_ {
+        self.data
+            .get_bounds()
+            .area()
+            .flat_map(|hex| {
+                EdgePosition::around(hex)
+                    .into_iter()
+                    .filter(move |edge| edge.structural_owner() == hex)
+            })
+            .filter(|edge| self.contains(*edge))
+    }
+
     pub fn data(&self) -> impl Iterator<Item = &T> {
-        self.data.data().flat_map(|((a, b, c), _)| [a, b, c]).flatten()
+        self.data
+            .data()
+            .flat_map(|((a, b, c), _)| [a, b, c])
+            .flatten()
     }
 }
 
+/// The rectangle around the hexes the edges of the shape are stored with, which reaches past the shape itself.
+fn storage_bounds(shape: &HexShape) -> HexBounds {
+    let hexes = shape.bounds();
+    let mut owners = hexes
+        .area()
+        .filter(|hex| shape.contains(*hex))
+        .flat_map(EdgePosition::around)
+        .map(|edge| edge.structural_owner());
+
+    let mut bounds = HexBounds::at(owners.next().unwrap_or(hexes.get_top_left()));
+    for owner in owners {
+        bounds.expand(owner);
+    }
+
+    bounds
+}
+
 impl<T> Index<EdgePosition> for EdgeTable<T> {
     type Output = T;
 
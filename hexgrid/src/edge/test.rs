@@ -0,0 +1,216 @@
+use crate::{
+    edge::{
+        iterators::{ring::EdgeRing, spiral::EdgeSpiral},
+        position::{EdgeOrientation, EdgePosition},
+        table::EdgeTable,
+    },
+    hex::{
+        bounds::HexBounds,
+        iterators::spiral::HexSpiral,
+        position::{HexPosition, ReflectionAxis},
+        shape::HexShape,
+    },
+};
+
+#[test]
+fn test_edge_neighboring_hex() {
+    // Covers hexes on both sides of the origin, where the conversion back from an edge used to round the wrong way.
+    for hex in HexPosition::ORIGIN.hexes_within(3) {
+        for edge in EdgePosition::around(hex) {
+            assert!(edge.neighboring_hex().contains(&hex));
+        }
+    }
+}
+
+#[test]
+fn test_edge_distance() {
+    let start = HexPosition::ORIGIN + HexPosition::UP_RIGHT;
+    let edges: [EdgePosition; 3] = [
+        (start + EdgeOrientation::TOP_LEFT).into(),
+        (start + EdgeOrientation::TOP_RIGHT).into(),
+        (start + EdgeOrientation::RIGHT).into(),
+    ];
+
+    for edge in edges {
+        let mut previous = Vec::new();
+        for steps in 0..5 {
+            let within = edge.edges_within(steps);
+            for other in within.iter().filter(|e| !previous.contains(*e)) {
+                assert_eq!(edge.distance(*other), steps);
+                assert_eq!(other.distance(edge), steps);
+            }
+
+            previous = within;
+        }
+    }
+}
+
+#[test]
+fn test_edge_symmetry() {
+    let center = HexPosition::ORIGIN + HexPosition::UP_RIGHT;
+    let edge: EdgePosition = (center + HexPosition::LEFT + EdgeOrientation::TOP_LEFT).into();
+
+    let orientation = |edge: EdgePosition| match edge {
+        EdgePosition::Even(_) => 0,
+        EdgePosition::Odd(_) => 1,
+        EdgePosition::Positive(_) => 2,
+    };
+
+    for turns in -6..12 {
+        let rotated = edge.rotate(center, turns);
+        assert_eq!(orientation(rotated), turns.rem_euclid(3));
+        assert_eq!(rotated.rotate(center, -turns), edge);
+    }
+
+    for (axis, expected) in [
+        (ReflectionAxis::Horizontal, 1),
+        (ReflectionAxis::Rising, 2),
+        (ReflectionAxis::Falling, 0),
+    ] {
+        let reflected = edge.reflect(center, axis);
+        assert_eq!(orientation(reflected), expected);
+        assert_eq!(reflected.reflect(center, axis), edge);
+    }
+}
+
+#[test]
+fn test_edge_ring() {
+    // a board with only one row has sides of length 0
+    for (shortest, longest, count) in [(3, 5, 30), (3, 6, 38), (2, 3, 18), (1, 1, 6), (2, 2, 10)] {
+        let board = HexShape::Hexagon {
+            top_left: HexPosition::ORIGIN,
+            shortest,
+            longest,
+        };
+        let ring: Vec<EdgePosition> = EdgeRing::new(shortest, longest).collect();
+        assert_eq!(ring.len(), count);
+        assert_eq!(
+            ring.first(),
+            Some(&(HexPosition::ORIGIN + EdgeOrientation::TOP_LEFT).into())
+        );
+
+        for (i, edge) in ring.iter().enumerate() {
+            let next = ring[(i + 1) % ring.len()];
+            assert!(edge.is_neighbor(next));
+            assert!(!ring[..i].contains(edge));
+            assert_eq!(
+                edge.neighboring_hex()
+                    .iter()
+                    .filter(|h| board.contains(**h))
+                    .count(),
+                1
+            );
+        }
+    }
+}
+
+#[test]
+fn test_edge_spiral() {
+    for (shortest, longest, count) in [(3, 5, 72), (3, 6, 109), (2, 3, 30)] {
+        let edges: Vec<EdgePosition> = EdgeSpiral::new(shortest, longest).collect();
+        assert_eq!(edges.len(), count);
+
+        for (i, edge) in edges.iter().enumerate() {
+            assert!(!edges[..i].contains(edge));
+        }
+
+        for hex in HexSpiral::new(shortest, longest) {
+            assert!(EdgePosition::around(hex).iter().all(|e| edges.contains(e)));
+        }
+    }
+}
+
+#[test]
+fn test_shaped_edge_table() {
+    let shape = HexShape::Hexagon {
+        top_left: HexPosition::ORIGIN,
+        shortest: 3,
+        longest: 6,
+    };
+    let edges: Vec<EdgePosition> = EdgeSpiral::new(3, 6).collect();
+
+    for mut table in [
+        EdgeTable::<usize>::with_shape(shape.clone()),
+        EdgeTable::<usize>::sparse(shape.clone()),
+    ] {
+        let positions: Vec<EdgePosition> = table.positions().collect();
+        assert_eq!(positions.len(), edges.len());
+        assert!(positions.iter().all(|e| edges.contains(e)));
+
+        for (i, edge) in edges.iter().enumerate() {
+            table.set(*edge, i).expect("Edge is on the board!");
+        }
+        for (i, edge) in edges.iter().enumerate() {
+            assert_eq!(table[*edge], i);
+        }
+
+        let outside_hex: HexPosition = HexPosition::ORIGIN + HexPosition::UP_LEFT * 2;
+        let outside: EdgePosition = (outside_hex + EdgeOrientation::RIGHT).into();
+        assert!(!table.contains(outside));
+        assert!(table.set(outside, 0).is_err());
+        assert_eq!(table.data().count(), edges.len());
+    }
+}
+
+#[test]
+fn test_odd_row_edge_table() {
+    let top_left = HexPosition::ORIGIN + HexPosition::DOWN_LEFT;
+    let mut bounds = HexBounds::at(top_left);
+    bounds.expand(top_left + HexPosition::RIGHT * 3 + HexPosition::DOWN_LEFT * 2);
+
+    for shape in [
+        HexShape::Hexagon {
+            top_left,
+            shortest: 3,
+            longest: 6,
+        },
+        HexShape::Rectangle(bounds),
+    ] {
+        let mut edges: Vec<EdgePosition> = Vec::new();
+        for hex in shape.bounds().area().filter(|p| shape.contains(*p)) {
+            for edge in EdgePosition::around(hex) {
+                if !edges.contains(&edge) {
+                    edges.push(edge);
+                }
+            }
+        }
+
+        for mut table in [
+            EdgeTable::<usize>::with_shape(shape.clone()),
+            EdgeTable::<usize>::sparse(shape.clone()),
+        ] {
+            let positions: Vec<EdgePosition> = table.positions().collect();
+            assert_eq!(positions.len(), edges.len());
+            assert!(positions.iter().all(|e| edges.contains(e)));
+
+            for (i, edge) in edges.iter().enumerate() {
+                table.set(*edge, i).expect("Edge is on the board!");
+            }
+            for (i, edge) in edges.iter().enumerate() {
+                assert_eq!(table[*edge], i);
+            }
+            assert_eq!(table.data().count(), edges.len());
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_edge_deserialization() {
+    for hex in HexPosition::ORIGIN.hexes_within(3) {
+        for edge in EdgePosition::around(hex) {
+            let json = serde_json::to_string(&edge).expect("Edge serializes!");
+            let restored: EdgePosition = serde_json::from_str(&json).expect("Edge is valid!");
+            assert_eq!(restored, edge);
+        }
+    }
+
+    // Coordinates between edges, and ones far enough out to overflow once a hex is added.
+    for json in [
+        r#"{"Even":{"rights":1,"downs":0}}"#,
+        r#"{"Positive":{"rights":0,"downs":0}}"#,
+        r#"{"Odd":{"rights":-2147483648,"downs":0}}"#,
+    ] {
+        assert!(serde_json::from_str::<EdgePosition>(json).is_err());
+    }
+}
@@ -2,7 +2,8 @@ use crate::{corner::bounds::CornerBounds, edge::bounds::EdgeBounds};
 
 use super::position::{HexPosition, HorizontalDisplacement};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HexBounds {
     top_left: HexPosition,
     bottom_right: HexPosition,
@@ -16,6 +17,14 @@ impl HexBounds {
         }
     }
 
+    /// Bounds holding nothing but the given position, to be grown with `expand`.
+    pub fn at(position: HexPosition) -> Self {
+        HexBounds {
+            top_left: position,
+            bottom_right: position,
+        }
+    }
+
     pub fn get_top_left(&self) -> HexPosition {
         self.top_left
     }
@@ -144,14 +153,10 @@ impl<'a> Iterator for HexArea<'a> {
                 HorizontalDisplacement::Unshifted(_) => current_position += HexPosition::DOWN_LEFT,
             }
 
+            // Every row covers the same raw `rights`, so the row starts back at those of the top left, whichever rows
+            // the corners are on.
             current_position += HexPosition::LEFT
-                * (self
-                    .parent
-                    .get_top_left()
-                    .horizontal_displacement(self.parent.get_bottom_right())
-                    .ceil()
-                    .abs()
-                    + 1);
+                * current_position.raw_horizontal_displacement(self.parent.get_top_left());
         }
 
         if !current_position.is_below(self.parent.get_bottom_right()) {
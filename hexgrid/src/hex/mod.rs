@@ -1,6 +1,7 @@
 pub mod bounds;
 pub mod iterators;
 pub mod position;
+pub mod shape;
 pub mod table;
 
 #[cfg(test)]
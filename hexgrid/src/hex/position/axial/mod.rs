@@ -0,0 +1,78 @@
+use core::fmt;
+
+use crate::hex::position::{HexPosition, cube::CubePosition};
+
+pub mod op_add;
+pub mod op_mul;
+pub mod op_sub;
+
+/// A hex position in axial coordinates, where `q` grows to the right and `r` grows down and to the left. Unlike the
+/// offset coordinates of `HexPosition`, every direction is the same vector on every row.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AxialPosition {
+    q: i32,
+    r: i32,
+}
+
+impl AxialPosition {
+    pub const ORIGIN: AxialPosition = AxialPosition { q: 0, r: 0 };
+
+    pub const RIGHT: AxialPosition = AxialPosition { q: 1, r: 0 };
+
+    pub const DOWN_RIGHT: AxialPosition = AxialPosition { q: 0, r: 1 };
+
+    pub const DOWN_LEFT: AxialPosition = AxialPosition { q: -1, r: 1 };
+
+    pub const LEFT: AxialPosition = AxialPosition { q: -1, r: 0 };
+
+    pub const UP_LEFT: AxialPosition = AxialPosition { q: 0, r: -1 };
+
+    pub const UP_RIGHT: AxialPosition = AxialPosition { q: 1, r: -1 };
+
+    pub fn new(q: i32, r: i32) -> Self {
+        AxialPosition { q, r }
+    }
+
+    pub fn q(&self) -> i32 {
+        self.q
+    }
+
+    pub fn r(&self) -> i32 {
+        self.r
+    }
+}
+
+impl From<HexPosition> for AxialPosition {
+    fn from(position: HexPosition) -> Self {
+        // Odd rows are shifted half a hex to the left, which `downs & 1` rounds away.
+        AxialPosition {
+            q: position.rights - (position.downs + (position.downs & 1)) / 2,
+            r: position.downs,
+        }
+    }
+}
+
+impl From<AxialPosition> for HexPosition {
+    fn from(position: AxialPosition) -> Self {
+        HexPosition {
+            rights: position.q + (position.r + (position.r & 1)) / 2,
+            downs: position.r,
+        }
+    }
+}
+
+impl From<CubePosition> for AxialPosition {
+    fn from(position: CubePosition) -> Self {
+        AxialPosition {
+            q: position.q(),
+            r: position.r(),
+        }
+    }
+}
+
+impl fmt::Display for AxialPosition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "AxialPosition [q: {}, r: {}]", self.q, self.r)
+    }
+}
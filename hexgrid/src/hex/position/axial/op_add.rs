@@ -0,0 +1,21 @@
+use std::ops::{Add, AddAssign};
+
+use super::AxialPosition;
+
+impl Add for AxialPosition {
+    type Output = AxialPosition;
+
+    fn add(self, rhs: AxialPosition) -> AxialPosition {
+        AxialPosition {
+            q: self.q + rhs.q,
+            r: self.r + rhs.r,
+        }
+    }
+}
+
+impl AddAssign for AxialPosition {
+    fn add_assign(&mut self, rhs: Self) {
+        self.q += rhs.q;
+        self.r += rhs.r;
+    }
+}
@@ -0,0 +1,49 @@
+use std::ops::{Mul, MulAssign};
+
+use super::AxialPosition;
+
+macro_rules! scalar_operations {
+    ($scalar: ty) => {
+        impl Mul<$scalar> for AxialPosition {
+            type Output = AxialPosition;
+
+            fn mul(self, rhs: $scalar) -> AxialPosition {
+                AxialPosition {
+                    q: self.q * rhs as i32,
+                    r: self.r * rhs as i32,
+                }
+            }
+        }
+
+        impl MulAssign<$scalar> for AxialPosition {
+            fn mul_assign(&mut self, rhs: $scalar) {
+                self.q *= rhs as i32;
+                self.r *= rhs as i32;
+            }
+        }
+
+        impl Mul<AxialPosition> for $scalar {
+            type Output = AxialPosition;
+
+            fn mul(self, rhs: AxialPosition) -> AxialPosition {
+                AxialPosition {
+                    q: rhs.q * self as i32,
+                    r: rhs.r * self as i32,
+                }
+            }
+        }
+    };
+}
+
+scalar_operations!(isize);
+scalar_operations!(usize);
+scalar_operations!(i128);
+scalar_operations!(u128);
+scalar_operations!(i64);
+scalar_operations!(u64);
+scalar_operations!(i32);
+scalar_operations!(u32);
+scalar_operations!(i16);
+scalar_operations!(u16);
+scalar_operations!(i8);
+scalar_operations!(u8);
@@ -0,0 +1,21 @@
+use std::ops::{Sub, SubAssign};
+
+use super::AxialPosition;
+
+impl Sub for AxialPosition {
+    type Output = AxialPosition;
+
+    fn sub(self, rhs: AxialPosition) -> AxialPosition {
+        AxialPosition {
+            q: self.q - rhs.q,
+            r: self.r - rhs.r,
+        }
+    }
+}
+
+impl SubAssign for AxialPosition {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.q -= rhs.q;
+        self.r -= rhs.r;
+    }
+}
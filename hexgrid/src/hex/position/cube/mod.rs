@@ -0,0 +1,190 @@
+use core::fmt;
+
+use crate::hex::position::{HexPosition, ReflectionAxis, axial::AxialPosition};
+
+pub mod op_add;
+pub mod op_mul;
+pub mod op_sub;
+
+/// A hex position in cube coordinates. It is an axial position with the redundant `s = -q - r` stored alongside, which
+/// makes the three axes of the grid symmetric.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CubePosition {
+    q: i32,
+    r: i32,
+    s: i32,
+}
+
+impl CubePosition {
+    pub const ORIGIN: CubePosition = CubePosition { q: 0, r: 0, s: 0 };
+
+    pub const RIGHT: CubePosition = CubePosition { q: 1, r: 0, s: -1 };
+
+    pub const DOWN_RIGHT: CubePosition = CubePosition { q: 0, r: 1, s: -1 };
+
+    pub const DOWN_LEFT: CubePosition = CubePosition { q: -1, r: 1, s: 0 };
+
+    pub const LEFT: CubePosition = CubePosition { q: -1, r: 0, s: 1 };
+
+    pub const UP_LEFT: CubePosition = CubePosition { q: 0, r: -1, s: 1 };
+
+    pub const UP_RIGHT: CubePosition = CubePosition { q: 1, r: -1, s: 0 };
+
+    /// Returns `None` unless `q + r + s == 0`.
+    pub fn new(q: i32, r: i32, s: i32) -> Option<Self> {
+        (q + r + s == 0).then_some(CubePosition { q, r, s })
+    }
+
+    pub fn q(&self) -> i32 {
+        self.q
+    }
+
+    pub fn r(&self) -> i32 {
+        self.r
+    }
+
+    pub fn s(&self) -> i32 {
+        self.s
+    }
+
+    /// The number of steps between neighboring hexes it takes to get from `self` to `other`.
+    pub fn distance(&self, other: Self) -> u32 {
+        (self.q.abs_diff(other.q) + self.r.abs_diff(other.r) + self.s.abs_diff(other.s)) / 2
+    }
+
+    /// Every position at most `radius` steps away, including `self`.
+    pub fn within(self, radius: u32) -> impl Iterator<Item = CubePosition> {
+        let radius = radius as i32;
+        (-radius..=radius).flat_map(move |q| {
+            ((-radius).max(-q - radius)..=radius.min(-q + radius))
+                .map(move |r| self + CubePosition { q, r, s: -q - r })
+        })
+    }
+
+    /// The positions on the straight line from `self` to `other`, both included, each a neighbor of the one before.
+    pub fn line_to(self, other: Self) -> impl Iterator<Item = CubePosition> {
+        let distance = self.distance(other);
+        (0..=distance).map(move |step| {
+            if distance == 0 {
+                return self;
+            }
+
+            let t = step as f64 / distance as f64;
+            // Nudging the line keeps it from running exactly along the edge between two hexes, where rounding would
+            // have to break a tie.
+            CubePosition::round(
+                lerp(self.q, other.q, t) + 1e-6,
+                lerp(self.r, other.r, t) + 2e-6,
+                lerp(self.s, other.s, t) - 3e-6,
+            )
+        })
+    }
+
+    /// Rotates by 60 degrees around the origin for every turn, clockwise for positive turns.
+    pub fn rotate(self, turns: i32) -> Self {
+        let CubePosition { q, r, s } = self;
+        match turns.rem_euclid(6) {
+            0 => self,
+            1 => CubePosition {
+                q: -r,
+                r: -s,
+                s: -q,
+            },
+            2 => CubePosition { q: s, r: q, s: r },
+            3 => CubePosition {
+                q: -q,
+                r: -r,
+                s: -s,
+            },
+            4 => CubePosition { q: r, r: s, s: q },
+            _ => CubePosition {
+                q: -s,
+                r: -q,
+                s: -r,
+            },
+        }
+    }
+
+    /// Mirrors across the axis through the origin.
+    pub fn reflect(self, axis: ReflectionAxis) -> Self {
+        let CubePosition { q, r, s } = self;
+        match axis {
+            ReflectionAxis::Horizontal => CubePosition {
+                q: -s,
+                r: -r,
+                s: -q,
+            },
+            ReflectionAxis::Rising => CubePosition {
+                q: -r,
+                r: -q,
+                s: -s,
+            },
+            ReflectionAxis::Falling => CubePosition {
+                q: -q,
+                r: -s,
+                s: -r,
+            },
+        }
+    }
+
+    /// Rounds each coordinate, then fixes up the one that moved the most so the coordinates still add up to zero.
+    fn round(q: f64, r: f64, s: f64) -> Self {
+        let (mut rounded_q, mut rounded_r, mut rounded_s) = (q.round(), r.round(), s.round());
+        let (diff_q, diff_r, diff_s) = (
+            (rounded_q - q).abs(),
+            (rounded_r - r).abs(),
+            (rounded_s - s).abs(),
+        );
+
+        if diff_q > diff_r && diff_q > diff_s {
+            rounded_q = -rounded_r - rounded_s;
+        } else if diff_r > diff_s {
+            rounded_r = -rounded_q - rounded_s;
+        } else {
+            rounded_s = -rounded_q - rounded_r;
+        }
+
+        CubePosition {
+            q: rounded_q as i32,
+            r: rounded_r as i32,
+            s: rounded_s as i32,
+        }
+    }
+}
+
+fn lerp(a: i32, b: i32, t: f64) -> f64 {
+    a as f64 + (b - a) as f64 * t
+}
+
+impl From<AxialPosition> for CubePosition {
+    fn from(position: AxialPosition) -> Self {
+        CubePosition {
+            q: position.q(),
+            r: position.r(),
+            s: -position.q() - position.r(),
+        }
+    }
+}
+
+impl From<HexPosition> for CubePosition {
+    fn from(position: HexPosition) -> Self {
+        AxialPosition::from(position).into()
+    }
+}
+
+impl From<CubePosition> for HexPosition {
+    fn from(position: CubePosition) -> Self {
+        AxialPosition::from(position).into()
+    }
+}
+
+impl fmt::Display for CubePosition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "CubePosition [q: {}, r: {}, s: {}]",
+            self.q, self.r, self.s
+        )
+    }
+}
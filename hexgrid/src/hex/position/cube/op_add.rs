@@ -0,0 +1,23 @@
+use std::ops::{Add, AddAssign};
+
+use super::CubePosition;
+
+impl Add for CubePosition {
+    type Output = CubePosition;
+
+    fn add(self, rhs: CubePosition) -> CubePosition {
+        CubePosition {
+            q: self.q + rhs.q,
+            r: self.r + rhs.r,
+            s: self.s + rhs.s,
+        }
+    }
+}
+
+impl AddAssign for CubePosition {
+    fn add_assign(&mut self, rhs: Self) {
+        self.q += rhs.q;
+        self.r += rhs.r;
+        self.s += rhs.s;
+    }
+}
@@ -0,0 +1,52 @@
+use std::ops::{Mul, MulAssign};
+
+use super::CubePosition;
+
+macro_rules! scalar_operations {
+    ($scalar: ty) => {
+        impl Mul<$scalar> for CubePosition {
+            type Output = CubePosition;
+
+            fn mul(self, rhs: $scalar) -> CubePosition {
+                CubePosition {
+                    q: self.q * rhs as i32,
+                    r: self.r * rhs as i32,
+                    s: self.s * rhs as i32,
+                }
+            }
+        }
+
+        impl MulAssign<$scalar> for CubePosition {
+            fn mul_assign(&mut self, rhs: $scalar) {
+                self.q *= rhs as i32;
+                self.r *= rhs as i32;
+                self.s *= rhs as i32;
+            }
+        }
+
+        impl Mul<CubePosition> for $scalar {
+            type Output = CubePosition;
+
+            fn mul(self, rhs: CubePosition) -> CubePosition {
+                CubePosition {
+                    q: rhs.q * self as i32,
+                    r: rhs.r * self as i32,
+                    s: rhs.s * self as i32,
+                }
+            }
+        }
+    };
+}
+
+scalar_operations!(isize);
+scalar_operations!(usize);
+scalar_operations!(i128);
+scalar_operations!(u128);
+scalar_operations!(i64);
+scalar_operations!(u64);
+scalar_operations!(i32);
+scalar_operations!(u32);
+scalar_operations!(i16);
+scalar_operations!(u16);
+scalar_operations!(i8);
+scalar_operations!(u8);
@@ -0,0 +1,23 @@
+use std::ops::{Sub, SubAssign};
+
+use super::CubePosition;
+
+impl Sub for CubePosition {
+    type Output = CubePosition;
+
+    fn sub(self, rhs: CubePosition) -> CubePosition {
+        CubePosition {
+            q: self.q - rhs.q,
+            r: self.r - rhs.r,
+            s: self.s - rhs.s,
+        }
+    }
+}
+
+impl SubAssign for CubePosition {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.q -= rhs.q;
+        self.r -= rhs.r;
+        self.s -= rhs.s;
+    }
+}
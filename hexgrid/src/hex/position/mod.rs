@@ -1,18 +1,60 @@
 use core::fmt;
 
+pub use axial::AxialPosition;
+pub use cube::CubePosition;
 pub use horizontal_displacement::HorizontalDisplacement;
+pub use reflection_axis::ReflectionAxis;
 
+pub mod axial;
+pub mod cube;
 pub mod horizontal_displacement;
 pub mod op_add;
 pub mod op_mul;
 pub mod op_sub;
-
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub mod reflection_axis;
+
+/// Positions further than this from the origin are rejected when deserialized, so arithmetic on them cannot overflow.
+#[cfg(feature = "serde")]
+pub(crate) const MAX_COORDINATE: u32 = 1 << 24;
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "UncheckedHexPosition")
+)]
 pub struct HexPosition {
     rights: i32,
     downs: i32,
 }
 
+/// A hex as it is read, before its coordinates are checked.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(rename = "HexPosition")]
+struct UncheckedHexPosition {
+    rights: i32,
+    downs: i32,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<UncheckedHexPosition> for HexPosition {
+    type Error = &'static str;
+
+    fn try_from(value: UncheckedHexPosition) -> Result<Self, Self::Error> {
+        if value.rights.unsigned_abs() > MAX_COORDINATE
+            || value.downs.unsigned_abs() > MAX_COORDINATE
+        {
+            return Err("hex coordinates are out of range");
+        }
+
+        Ok(HexPosition {
+            rights: value.rights,
+            downs: value.downs,
+        })
+    }
+}
+
 impl HexPosition {
     pub const ORIGIN: HexPosition = HexPosition {
         rights: 0,
@@ -114,10 +156,40 @@ impl HexPosition {
     pub fn is_above_or_equal(&self, other: Self) -> bool {
         self.vertical_displacement(other) <= 0
     }
+
+    /// The number of steps between neighboring hexes it takes to get from `self` to `other`.
+    pub fn distance(&self, other: Self) -> u32 {
+        CubePosition::from(*self).distance(other.into())
+    }
+
+    /// Every hex at most `radius` steps away, including `self`.
+    pub fn hexes_within(self, radius: u32) -> impl Iterator<Item = HexPosition> {
+        CubePosition::from(self).within(radius).map(Into::into)
+    }
+
+    /// The hexes on the straight line from `self` to `other`, both included, each a neighbor of the one before.
+    pub fn line_to(self, other: Self) -> impl Iterator<Item = HexPosition> {
+        CubePosition::from(self)
+            .line_to(other.into())
+            .map(Into::into)
+    }
+
+    /// Rotates by 60 degrees around `center` for every turn, clockwise for positive turns.
+    pub fn rotate(self, center: Self, turns: i32) -> Self {
+        center + HexPosition::from(CubePosition::from(self - center).rotate(turns))
+    }
+
+    pub fn reflect(self, center: Self, axis: ReflectionAxis) -> Self {
+        center + HexPosition::from(CubePosition::from(self - center).reflect(axis))
+    }
 }
 
 impl fmt::Display for HexPosition {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        writeln!(f, "HexPosition [rights: {}, downs: {}]", self.rights, self.downs)
+        writeln!(
+            f,
+            "HexPosition [rights: {}, downs: {}]",
+            self.rights, self.downs
+        )
     }
 }
@@ -0,0 +1,11 @@
+/// A line through the center of a hex and two of its neighbors, which positions can be mirrored across. Mirroring
+/// across any other symmetry axis of the grid is one of these followed by a rotation.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ReflectionAxis {
+    /// Through the `LEFT` and `RIGHT` neighbors, swapping top and bottom.
+    Horizontal,
+    /// Through the `DOWN_LEFT` and `UP_RIGHT` neighbors.
+    Rising,
+    /// Through the `UP_LEFT` and `DOWN_RIGHT` neighbors.
+    Falling,
+}
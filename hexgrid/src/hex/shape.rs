@@ -0,0 +1,103 @@
+use super::{
+    bounds::HexBounds,
+    position::{AxialPosition, HexPosition},
+};
+
+/// The hexes a map is made of. Tables built from a shape only accept the hexes in it, as well as the corners and
+/// edges touching them, even where the rectangle around the shape would hold more.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum HexShape {
+    /// Rows growing by one hex from `shortest` to `longest` and shrinking back, laid out like `HexSpiral` with its top
+    /// left hex at `top_left`.
+    Hexagon {
+        top_left: HexPosition,
+        shortest: u32,
+        longest: u32,
+    },
+    Rectangle(HexBounds),
+    /// Any hexes, such as islands or irregular maps. Use `HexShape::set` to build one without duplicates.
+    Set(Vec<HexPosition>),
+}
+
+impl HexShape {
+    pub fn set(positions: impl IntoIterator<Item = HexPosition>) -> Self {
+        let mut set = Vec::new();
+        for position in positions {
+            if !set.contains(&position) {
+                set.push(position);
+            }
+        }
+
+        HexShape::Set(set)
+    }
+
+    pub fn contains(&self, position: HexPosition) -> bool {
+        match self {
+            Self::Hexagon {
+                top_left,
+                shortest,
+                longest,
+            } => {
+                let relative = AxialPosition::from(position - *top_left);
+                match hexagon_row(*shortest, *longest, relative.r()) {
+                    Some((first, last)) => (first..=last).contains(&relative.q()),
+                    None => false,
+                }
+            }
+            Self::Rectangle(bounds) => bounds.contains(position),
+            Self::Set(positions) => positions.contains(&position),
+        }
+    }
+
+    /// The smallest rectangle around the shape.
+    pub fn bounds(&self) -> HexBounds {
+        match self {
+            Self::Hexagon {
+                top_left,
+                shortest,
+                longest,
+            } => {
+                let mut bounds = HexBounds::at(*top_left);
+                let mut row = 0;
+                while let Some((first, last)) = hexagon_row(*shortest, *longest, row) {
+                    bounds.expand(*top_left + HexPosition::from(AxialPosition::new(first, row)));
+                    bounds.expand(*top_left + HexPosition::from(AxialPosition::new(last, row)));
+                    row += 1;
+                }
+
+                bounds
+            }
+            Self::Rectangle(bounds) => bounds.clone(),
+            Self::Set(positions) => {
+                let Some(first) = positions.first() else {
+                    return HexBounds::new();
+                };
+
+                let mut bounds = HexBounds::at(*first);
+                for position in positions {
+                    bounds.expand(*position);
+                }
+
+                bounds
+            }
+        }
+    }
+}
+
+/// The first and last axial `q` of a row of a hexagon, relative to its top left hex.
+fn hexagon_row(shortest: u32, longest: u32, row: i32) -> Option<(i32, i32)> {
+    if shortest == 0 || longest < shortest {
+        return None;
+    }
+
+    let growth = (longest - shortest) as i32;
+    if !(0..=2 * growth).contains(&row) {
+        return None;
+    }
+
+    let first = -row.min(growth);
+    let width = shortest as i32 + growth - (row - growth).abs();
+
+    Some((first, first + width - 1))
+}
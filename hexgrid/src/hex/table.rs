@@ -1,14 +1,24 @@
+use std::collections::HashMap;
 use std::fmt::Debug;
 use std::ops::{Index, IndexMut};
 
-use crate::hex::bounds::HexArea;
+use super::{bounds::HexBounds, position::HexPosition, shape::HexShape};
 
-use super::{bounds::HexBounds, position::HexPosition};
-
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HexTable<T> {
-    data: Vec<Option<T>>,
+    storage: HexStorage<T>,
     bounds: HexBounds,
+    shape: HexShape,
+}
+
+/// Where a table keeps its data. A dense table allocates a slot for every hex of the rectangle around its shape,
+/// while a sparse one only holds the hexes which have been set.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum HexStorage<T> {
+    Dense(Vec<Option<T>>),
+    Sparse(#[cfg_attr(feature = "serde", serde(with = "entries"))] HashMap<HexPosition, T>),
 }
 
 impl HexBounds {
@@ -19,61 +29,88 @@ impl HexBounds {
 
 impl<T> HexTable<T> {
     pub fn new(bounds: HexBounds) -> Self {
+        Self::with_shape(HexShape::Rectangle(bounds))
+    }
+
+    /// A dense table which only accepts the hexes in the shape.
+    pub fn with_shape(shape: HexShape) -> Self {
+        let bounds = shape.bounds();
         let mut data = Vec::with_capacity(bounds.get_size());
         data.resize_with(bounds.get_size(), Default::default);
 
-        HexTable { data, bounds }
+        HexTable {
+            storage: HexStorage::Dense(data),
+            bounds,
+            shape,
+        }
+    }
+
+    /// A sparse table which only accepts the hexes in the shape, for maps which fill little of the rectangle around
+    /// them.
+    pub fn sparse(shape: HexShape) -> Self {
+        HexTable {
+            storage: HexStorage::Sparse(HashMap::new()),
+            bounds: shape.bounds(),
+            shape,
+        }
     }
 
+    /// The rectangle around the shape of the table.
     pub fn get_bounds(&self) -> &HexBounds {
         &self.bounds
     }
 
-    fn calc_index(&self, position: HexPosition) -> Option<usize> {
-        if !self.bounds.contains(position) {
-            return None;
-        }
-
-        let rights: isize = position
-            .horizontal_displacement(self.bounds.get_top_left())
-            .ceil()
-            .try_into()
-            .ok()?;
-
-        let downs: isize = position
-            .vertical_displacement(self.bounds.get_top_left())
-            .try_into()
-            .ok()?;
-
-        let length: isize = self.bounds.get_width().try_into().ok()?;
+    pub fn get_shape(&self) -> &HexShape {
+        &self.shape
+    }
 
-        downs
-            .checked_mul(length)?
-            .checked_add(rights)?
-            .try_into()
-            .ok()
+    pub fn contains(&self, position: HexPosition) -> bool {
+        self.bounds.contains(position) && self.shape.contains(position)
     }
 
     pub fn get(&self, position: HexPosition) -> Option<&T> {
-        self.data[self.calc_index(position)?].as_ref()
+        if !self.contains(position) {
+            return None;
+        }
+
+        match &self.storage {
+            HexStorage::Dense(data) => data[calc_index(&self.bounds, position)?].as_ref(),
+            HexStorage::Sparse(data) => data.get(&position),
+        }
     }
 
     pub fn get_mut(&mut self, position: HexPosition) -> Option<&mut T> {
-        let idx = self.calc_index(position)?;
-        self.data[idx].as_mut()
+        if !self.contains(position) {
+            return None;
+        }
+
+        match &mut self.storage {
+            HexStorage::Dense(data) => data[calc_index(&self.bounds, position)?].as_mut(),
+            HexStorage::Sparse(data) => data.get_mut(&position),
+        }
     }
 
     pub fn set(&mut self, position: HexPosition, data: T) -> Result<(), ()> {
-        if let Some(p) = self.calc_index(position) {
-            *self.data.get_mut(p).ok_or(())? = Some(data);
-            Ok(())
-        } else {
-            Err(())
+        if !self.contains(position) {
+            return Err(());
+        }
+
+        match &mut self.storage {
+            HexStorage::Dense(slots) => {
+                let p = calc_index(&self.bounds, position).ok_or(())?;
+                *slots.get_mut(p).ok_or(())? = Some(data);
+            }
+            HexStorage::Sparse(entries) => {
+                entries.insert(position, data);
+            }
         }
+
+        Ok(())
     }
 
-    pub fn positions(&self) -> HexArea<'_> {
-        self.bounds.area()
+    /// Every hex of the shape, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = HexPosition> + '_ {
+        self.bounds.area().filter(|p| self.shape.contains(*p))
     }
 
     pub fn data(&self) -> HexData<'_, T> {
@@ -96,14 +133,36 @@ impl<T> IndexMut<HexPosition> for HexTable<T> {
     }
 }
 
+/// Bounds cover the same raw `rights` on every row, so the index is taken from those rather than the shifted
+/// displacement, which would run past the end of every other row when the top left hex is on an odd row.
+fn calc_index(bounds: &HexBounds, position: HexPosition) -> Option<usize> {
+    let rights: isize = position
+        .raw_horizontal_displacement(bounds.get_top_left())
+        .try_into()
+        .ok()?;
+
+    let downs: isize = position
+        .vertical_displacement(bounds.get_top_left())
+        .try_into()
+        .ok()?;
+
+    let length: isize = bounds.get_width().try_into().ok()?;
+
+    downs
+        .checked_mul(length)?
+        .checked_add(rights)?
+        .try_into()
+        .ok()
+}
+
 pub struct HexData<'a, T> {
     parent: &'a HexTable<T>,
-    area: HexArea<'a>,
+    area: Box<dyn Iterator<Item = HexPosition> + 'a>,
 }
 
 impl<'a, T> HexData<'a, T> {
     fn new(parent: &'a HexTable<T>) -> Self {
-        let area = parent.bounds.area();
+        let area = Box::new(parent.positions());
         HexData { parent, area }
     }
 }
@@ -121,3 +180,43 @@ impl<'a, T> Iterator for HexData<'a, T> {
         None
     }
 }
+
+/// (De)serializes a sparse table as a list of entries, since formats like JSON only allow strings as map keys.
+#[cfg(feature = "serde")]
+mod entries {
+    use std::collections::HashMap;
+
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::hex::position::HexPosition;
+
+    pub fn serialize<S, T>(
+        entries: &HashMap<HexPosition, T>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: Serialize,
+    {
+        // Rows top to bottom, so the same table always gives the same output, whatever order the map is in.
+        let mut sorted: Vec<(&HexPosition, &T)> = entries.iter().collect();
+        sorted.sort_by_key(|(position, _)| {
+            (
+                position.vertical_displacement(HexPosition::ORIGIN),
+                position.raw_horizontal_displacement(HexPosition::ORIGIN),
+            )
+        });
+
+        serializer.collect_seq(sorted)
+    }
+
+    pub fn deserialize<'de, D, T>(deserializer: D) -> Result<HashMap<HexPosition, T>, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: Deserialize<'de>,
+    {
+        Ok(Vec::<(HexPosition, T)>::deserialize(deserializer)?
+            .into_iter()
+            .collect())
+    }
+}
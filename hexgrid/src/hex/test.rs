@@ -1,4 +1,10 @@
-use crate::hex::{bounds::HexBounds, position::HexPosition, table::HexTable};
+use crate::hex::{
+    bounds::HexBounds,
+    iterators::spiral::HexSpiral,
+    position::{AxialPosition, CubePosition, HexPosition, ReflectionAxis},
+    shape::HexShape,
+    table::HexTable,
+};
 
 #[test]
 fn test_hex_data() {
@@ -17,3 +23,293 @@ fn test_hex_data() {
 
     println!("hex_table: {:?}", hex_table)
 }
+
+#[test]
+fn test_coordinate_conversions() {
+    let directions = [
+        (
+            HexPosition::RIGHT,
+            AxialPosition::RIGHT,
+            CubePosition::RIGHT,
+        ),
+        (
+            HexPosition::DOWN_RIGHT,
+            AxialPosition::DOWN_RIGHT,
+            CubePosition::DOWN_RIGHT,
+        ),
+        (
+            HexPosition::DOWN_LEFT,
+            AxialPosition::DOWN_LEFT,
+            CubePosition::DOWN_LEFT,
+        ),
+        (HexPosition::LEFT, AxialPosition::LEFT, CubePosition::LEFT),
+        (
+            HexPosition::UP_LEFT,
+            AxialPosition::UP_LEFT,
+            CubePosition::UP_LEFT,
+        ),
+        (
+            HexPosition::UP_RIGHT,
+            AxialPosition::UP_RIGHT,
+            CubePosition::UP_RIGHT,
+        ),
+    ];
+
+    let start = HexPosition::ORIGIN + HexPosition::UP_LEFT * 3 + HexPosition::LEFT;
+    for position in HexSpiral::new(4, 7).map(|p| p + start) {
+        let axial = AxialPosition::from(position);
+        let cube = CubePosition::from(position);
+        assert_eq!(HexPosition::from(axial), position);
+        assert_eq!(HexPosition::from(cube), position);
+        assert_eq!(CubePosition::from(axial), cube);
+        assert_eq!(cube.q() + cube.r() + cube.s(), 0);
+
+        for (hex, axial_direction, cube_direction) in directions {
+            assert_eq!(AxialPosition::from(position + hex), axial + axial_direction);
+            assert_eq!(CubePosition::from(position - hex), cube - cube_direction);
+            assert_eq!(
+                AxialPosition::from(position + hex * 3),
+                axial + 3 * axial_direction
+            );
+            assert_eq!(CubePosition::from(hex * -2), cube_direction * -2);
+        }
+    }
+
+    assert_eq!(CubePosition::new(1, 1, 1), None);
+}
+
+#[test]
+fn test_hex_distance() {
+    let center = HexPosition::ORIGIN + HexPosition::DOWN_LEFT;
+    for radius in 0..5u32 {
+        let within: Vec<HexPosition> = center.hexes_within(radius).collect();
+        assert_eq!(within.len() as u32, 1 + 3 * radius * (radius + 1));
+        assert!(within.iter().all(|p| p.distance(center) <= radius));
+        assert_eq!(
+            within
+                .iter()
+                .filter(|p| p.distance(center) == radius)
+                .count() as u32,
+            (6 * radius).max(1)
+        );
+    }
+
+    assert_eq!(
+        center.distance(center + HexPosition::RIGHT * 2 + HexPosition::DOWN_LEFT * 3),
+        3
+    );
+
+    for target in center.hexes_within(6) {
+        let line: Vec<HexPosition> = center.line_to(target).collect();
+        assert_eq!(line.len() as u32, center.distance(target) + 1);
+        assert_eq!(line.first(), Some(&center));
+        assert_eq!(line.last(), Some(&target));
+        assert!(line.windows(2).all(|pair| pair[0].distance(pair[1]) == 1));
+    }
+}
+
+#[test]
+fn test_hex_symmetry() {
+    let center = HexPosition::ORIGIN + HexPosition::DOWN_LEFT;
+    let directions = [
+        HexPosition::RIGHT,
+        HexPosition::DOWN_RIGHT,
+        HexPosition::DOWN_LEFT,
+        HexPosition::LEFT,
+        HexPosition::UP_LEFT,
+        HexPosition::UP_RIGHT,
+    ];
+
+    for (i, direction) in directions.into_iter().enumerate() {
+        for turns in -6..12 {
+            let rotated = directions[(i as i32 + turns).rem_euclid(6) as usize];
+            assert_eq!((center + direction).rotate(center, turns), center + rotated);
+        }
+    }
+
+    assert_eq!(
+        (center + HexPosition::DOWN_RIGHT).reflect(center, ReflectionAxis::Horizontal),
+        center + HexPosition::UP_RIGHT
+    );
+    assert_eq!(
+        (center + HexPosition::RIGHT).reflect(center, ReflectionAxis::Rising),
+        center + HexPosition::UP_LEFT
+    );
+    assert_eq!(
+        (center + HexPosition::RIGHT).reflect(center, ReflectionAxis::Falling),
+        center + HexPosition::DOWN_LEFT
+    );
+
+    for position in center.hexes_within(4) {
+        for axis in [
+            ReflectionAxis::Horizontal,
+            ReflectionAxis::Rising,
+            ReflectionAxis::Falling,
+        ] {
+            let reflected = position.reflect(center, axis);
+            assert_eq!(reflected.reflect(center, axis), position);
+            assert_eq!(reflected.distance(center), position.distance(center));
+        }
+
+        for turns in 0..6 {
+            assert_eq!(
+                position.rotate(center, turns).distance(center),
+                position.distance(center)
+            );
+        }
+    }
+}
+
+#[test]
+fn test_hex_shape() {
+    for top_left in [
+        HexPosition::ORIGIN,
+        HexPosition::DOWN_LEFT + HexPosition::LEFT,
+    ] {
+        for (shortest, longest) in [(3, 5), (3, 6), (1, 1)] {
+            let shape = HexShape::Hexagon {
+                top_left,
+                shortest,
+                longest,
+            };
+            let board: Vec<HexPosition> = HexSpiral::new(shortest, longest)
+                .map(|p| p + top_left)
+                .collect();
+
+            let bounds = shape.bounds();
+            assert!(board.iter().all(|p| bounds.contains(*p)));
+            for position in bounds.area() {
+                assert_eq!(shape.contains(position), board.contains(&position));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_sparse_hex_table() {
+    let island = HexShape::set([
+        HexPosition::ORIGIN + HexPosition::RIGHT * 4,
+        HexPosition::ORIGIN + HexPosition::DOWN_RIGHT * 3,
+        HexPosition::ORIGIN + HexPosition::DOWN_RIGHT * 3,
+        HexPosition::ORIGIN + HexPosition::DOWN_LEFT * 5 + HexPosition::LEFT,
+    ]);
+    let hexagon = HexShape::Hexagon {
+        top_left: HexPosition::ORIGIN,
+        shortest: 3,
+        longest: 5,
+    };
+
+    for shape in [island, hexagon] {
+        let mut dense = HexTable::<usize>::with_shape(shape.clone());
+        let mut sparse = HexTable::<usize>::sparse(shape.clone());
+
+        let positions: Vec<HexPosition> = sparse.positions().collect();
+        assert_eq!(positions, dense.positions().collect::<Vec<_>>());
+        assert!(positions.iter().all(|p| shape.contains(*p)));
+
+        for (i, position) in positions.iter().enumerate() {
+            dense.set(*position, i).expect("Position is in the shape!");
+            sparse.set(*position, i).expect("Position is in the shape!");
+        }
+
+        for position in shape.bounds().area() {
+            assert_eq!(dense.get(position), sparse.get(position));
+            if !shape.contains(position) {
+                assert!(sparse.set(position, 0).is_err());
+                assert!(dense.set(position, 0).is_err());
+            }
+        }
+
+        sparse[positions[0]] += 10;
+        assert_eq!(sparse[positions[0]], 10);
+        assert_eq!(sparse.data().count(), positions.len());
+    }
+
+    assert_eq!(
+        HexTable::<usize>::sparse(HexShape::set([]))
+            .positions()
+            .count(),
+        0
+    );
+}
+
+#[test]
+fn test_hex_area() {
+    // Corners on rows of the same and of different parity, starting on even and on odd rows.
+    for top_left in [HexPosition::ORIGIN, HexPosition::DOWN_LEFT] {
+        for steps in [1, 2, 3] {
+            let mut bottom_right = top_left + HexPosition::RIGHT * 3;
+            for step in 0..steps {
+                bottom_right += if step % 2 == 0 {
+                    HexPosition::DOWN_LEFT
+                } else {
+                    HexPosition::DOWN_RIGHT
+                };
+            }
+
+            let mut bounds = HexBounds::at(top_left);
+            bounds.expand(bottom_right);
+            let area: Vec<HexPosition> = bounds.area().collect();
+
+            let width = bottom_right.raw_horizontal_displacement(top_left) + 1;
+            assert_eq!(area.len() as i32, width * (steps + 1));
+            for (i, position) in area.iter().enumerate() {
+                assert!(bounds.contains(*position));
+                assert!(!area[..i].contains(position));
+            }
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_sparse_hex_table_serialization() {
+    let shape = HexShape::Hexagon {
+        top_left: HexPosition::ORIGIN,
+        shortest: 3,
+        longest: 5,
+    };
+    let positions: Vec<HexPosition> = HexTable::<usize>::sparse(shape.clone())
+        .positions()
+        .collect();
+
+    let mut forward = HexTable::<usize>::sparse(shape.clone());
+    let mut backward = HexTable::<usize>::sparse(shape);
+    for (i, position) in positions.iter().enumerate() {
+        forward
+            .set(*position, i)
+            .expect("Position is in the shape!");
+    }
+    for (i, position) in positions.iter().enumerate().rev() {
+        backward
+            .set(*position, i)
+            .expect("Position is in the shape!");
+    }
+
+    let json = serde_json::to_string(&forward).expect("Table serializes!");
+    assert_eq!(
+        json,
+        serde_json::to_string(&backward).expect("Table serializes!")
+    );
+
+    let restored: HexTable<usize> = serde_json::from_str(&json).expect("Table deserializes!");
+    assert_eq!(
+        json,
+        serde_json::to_string(&restored).expect("Table serializes!")
+    );
+    for (i, position) in positions.iter().enumerate() {
+        assert_eq!(restored[*position], i);
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_hex_deserialization() {
+    for hex in HexPosition::ORIGIN.hexes_within(3) {
+        let json = serde_json::to_string(&hex).expect("Hex serializes!");
+        let restored: HexPosition = serde_json::from_str(&json).expect("Hex is valid!");
+        assert_eq!(restored, hex);
+    }
+
+    assert!(serde_json::from_str::<HexPosition>(r#"{"rights":2147483647,"downs":0}"#).is_err());
+}